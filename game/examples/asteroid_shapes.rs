@@ -0,0 +1,46 @@
+//! Dumps sample asteroid shapes of every material to SVG files
+//!
+//! Usage: `cargo run --example asteroid_shapes -- [output_dir] [count] [seed]`
+
+use std::{env, fs, path::PathBuf};
+
+use game::asteroid_shapes::{
+    generate_asteroid_vectors, shapes_svg, validate_polygon, AsteroidMaterial,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+const COLUMNS: usize = 8;
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let output_dir = PathBuf::from(args.next().unwrap_or_else(|| ".".to_string()));
+    let count: usize = args.next().and_then(|arg| arg.parse().ok()).unwrap_or(32);
+    let seed: u64 = args.next().and_then(|arg| arg.parse().ok()).unwrap_or(0);
+
+    fs::create_dir_all(&output_dir).expect("Failed to create output dir");
+
+    for material in [
+        AsteroidMaterial::Rock,
+        AsteroidMaterial::Ice,
+        AsteroidMaterial::Metal,
+        AsteroidMaterial::Crystal,
+        AsteroidMaterial::Volatile,
+    ] {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let profile = material.shape_profile();
+
+        let shapes: Vec<_> = (0..count)
+            .map(|_| generate_asteroid_vectors(&profile, &mut rng))
+            .collect();
+
+        let invalid = shapes
+            .iter()
+            .filter(|shape| !validate_polygon(shape))
+            .count();
+
+        let path = output_dir.join(format!("asteroids_{:?}.svg", material).to_lowercase());
+        fs::write(&path, shapes_svg(&shapes, COLUMNS)).expect("Failed to write svg");
+
+        println!("{}: {} shapes, {} invalid", path.display(), count, invalid);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+
+use bevy::prelude::*;
+
+use crate::{goal::Goal, score::Score, stats::RunStats, storage::data_file};
+
+const ACHIEVEMENTS_FILE: &str = "achievements.txt";
+
+/// Requirement checked against current run
+#[derive(Clone, Copy, Debug)]
+pub enum AchievementCondition {
+    GoalsReached(u32),
+    /// Run duration in seconds
+    Survive(f32),
+    Points(u64),
+    AsteroidsDestroyed(u32),
+    /// Destroy asteroid of at least this size level before taking any damage
+    FlawlessKill(i32),
+    Distance(f32),
+    /// Accuracy fraction after at least `shots` fired
+    Accuracy {
+        accuracy: f32,
+        shots: u32,
+    },
+}
+
+impl AchievementCondition {
+    pub fn satisfied(&self, stats: &RunStats, score: &Score, goal: &Goal) -> bool {
+        match *self {
+            Self::GoalsReached(goals) => goals <= goal.points,
+            Self::Survive(time) => time <= score.time(),
+            Self::Points(points) => points <= score.points(),
+            Self::AsteroidsDestroyed(count) => count <= stats.asteroids_total(),
+            Self::FlawlessKill(level) => level <= stats.flawless_level(),
+            Self::Distance(distance) => distance <= stats.distance(),
+            Self::Accuracy { accuracy, shots } => {
+                shots <= stats.shots() && accuracy <= stats.accuracy()
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Achievement {
+    /// Stable identifier used in save file
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub condition: AchievementCondition,
+}
+
+/// All achievements of the game
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "first_goal",
+        name: "Waypoint",
+        description: "Reach a goal",
+        condition: AchievementCondition::GoalsReached(1),
+    },
+    Achievement {
+        id: "goals_10",
+        name: "Navigator",
+        description: "Reach 10 goals",
+        condition: AchievementCondition::GoalsReached(10),
+    },
+    Achievement {
+        id: "survive_10m",
+        name: "Survivor",
+        description: "Survive 10 minutes",
+        condition: AchievementCondition::Survive(600.0),
+    },
+    Achievement {
+        id: "asteroids_100",
+        name: "Miner",
+        description: "Destroy 100 asteroids",
+        condition: AchievementCondition::AsteroidsDestroyed(100),
+    },
+    Achievement {
+        id: "flawless_level_4",
+        name: "Untouchable",
+        description: "Destroy a level-4 asteroid without taking damage",
+        condition: AchievementCondition::FlawlessKill(4),
+    },
+    Achievement {
+        id: "points_10000",
+        name: "High Roller",
+        description: "Score 10000 points",
+        condition: AchievementCondition::Points(10_000),
+    },
+    Achievement {
+        id: "distance_100km",
+        name: "Long Haul",
+        description: "Travel 100 000 units",
+        condition: AchievementCondition::Distance(100_000.0),
+    },
+    Achievement {
+        id: "accuracy_75",
+        name: "Sharpshooter",
+        description: "Keep 75% accuracy after 100 shots",
+        condition: AchievementCondition::Accuracy {
+            accuracy: 0.75,
+            shots: 100,
+        },
+    },
+];
+
+/// Unlocked achievements. Stored in platform data dir
+#[derive(Resource, Default, Clone, Debug)]
+pub struct AchievementProgress(HashSet<String>);
+
+impl AchievementProgress {
+    /// Loads progress. Missing file gives no unlocked achievements
+    pub fn load() -> Self {
+        let Some(content) =
+            data_file(ACHIEVEMENTS_FILE).and_then(|path| fs::read_to_string(path).ok())
+        else {
+            return Self::default();
+        };
+
+        Self(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = data_file(ACHIEVEMENTS_FILE) else {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "no data dir"));
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = fs::File::create(path)?;
+        for id in self.0.iter() {
+            writeln!(file, "{}", id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn unlocked(&self, achievement: &Achievement) -> bool {
+        self.0.contains(achievement.id)
+    }
+
+    /// Returns false if achievement was already unlocked
+    pub fn unlock(&mut self, achievement: &Achievement) -> bool {
+        self.0.insert(achievement.id.to_string())
+    }
+
+    pub fn count(&self) -> usize {
+        ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| self.unlocked(achievement))
+            .count()
+    }
+}
+
+/// Achievement has been unlocked just now
+pub struct AchievementUnlocked(pub &'static Achievement);
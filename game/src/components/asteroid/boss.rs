@@ -0,0 +1,184 @@
+use std::f32::consts::PI;
+use std::time::Duration;
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+use bevy_rapier2d::prelude::*;
+use physic_objects::prelude::*;
+
+use super::builder::AsteroidCreateInfoBuilder;
+use super::level::AsteroidSizeLevel;
+use super::material::AsteroidMaterial;
+use super::shape::AsteroidShapeProfile;
+use crate::components::common::DespawnOnOutOfRange;
+use crate::components::faction::Faction;
+use crate::components::health::{HealthBundle, Resistances};
+use crate::damage::DamageKind;
+use crate::entity::{EntityBuilder, EntityChildBuildDirector};
+use crate::math::RotateAroundZ;
+
+/// Boss has this times more health than usual asteroid of same level
+const BOSS_HEALTH_MULTIPLIER: f32 = 3.0;
+
+/// Huge asteroid with armour and periodic shedding of small asteroids
+#[derive(Component)]
+pub struct BossAsteroid {
+    shed_timer: Timer,
+    /// Goal points when boss appeared. Boss is left behind when next goal is reached
+    guarded_goal: u32,
+}
+
+impl BossAsteroid {
+    pub fn new(shed_period: f32, guarded_goal: u32) -> Self {
+        Self {
+            shed_timer: Timer::new(Duration::from_secs_f32(shed_period), TimerMode::Repeating),
+            guarded_goal,
+        }
+    }
+
+    pub fn guarded_goal(&self) -> u32 {
+        self.guarded_goal
+    }
+
+    /// Returns true when boss should shed asteroids
+    pub fn update(&mut self, delta: Duration) -> bool {
+        self.shed_timer.tick(delta).just_finished()
+    }
+}
+
+/// Boss takes no damage while it has armour segments
+#[derive(Component)]
+pub struct BossArmored;
+
+/// Resistances of armored boss: immune to everything
+pub fn boss_armored_resistances() -> Resistances {
+    [
+        DamageKind::Collision,
+        DamageKind::Projectile,
+        DamageKind::Explosion,
+        DamageKind::Burning,
+    ]
+    .into_iter()
+    .fold(Resistances::new(), |resistances, kind| {
+        resistances.with(kind, 1.0)
+    })
+}
+
+/// Armour plate attached to boss collider. Must be broken off before boss takes damage
+#[derive(Component)]
+pub struct ArmorSegment;
+
+#[derive(Builder)]
+pub struct ArmorSegmentCreateInfo {
+    /// Position relative to boss
+    position: Vec2,
+    radius: f32,
+    health: f32,
+}
+
+pub use ArmorSegmentCreateInfoBuilder as ArmorSegmentBuilder;
+
+impl EntityBuilder for ArmorSegmentBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        // Only collider without body, so segment is a part of boss body
+        commands
+            .insert(
+                CircleBuilder::default()
+                    .radius(info.radius)
+                    .build_primitive(),
+            )
+            .insert(Collider::ball(info.radius))
+            .insert(ColliderMassProperties::Density(
+                AsteroidMaterial::Metal.density(),
+            ))
+            .insert(Fill {
+                color: AsteroidMaterial::Metal.color(),
+                options: default(),
+            })
+            .insert(Stroke {
+                color: Color::BLACK,
+                options: default(),
+            })
+            .insert(ArmorSegment)
+            .insert(Faction::Environment)
+            .insert(HealthBundle::new(info.health))
+            // Slightly above boss body inside of its layer
+            .insert(TransformBundle::from(Transform::from_translation(
+                info.position.extend(0.1),
+            )))
+    }
+}
+
+#[derive(Builder)]
+pub struct BossAsteroidCreateInfo {
+    position: Vec2,
+    #[builder(default = "6")]
+    size_level: i32,
+    #[builder(default = "Vec2::ZERO")]
+    base_velocity: Vec2,
+    #[builder(default = "8")]
+    segments: usize,
+    /// Seconds between shedding small asteroids
+    #[builder(default = "6.0")]
+    shed_period: f32,
+    #[builder(default = "0")]
+    guarded_goal: u32,
+}
+
+pub use BossAsteroidCreateInfoBuilder as BossAsteroidBuilder;
+
+impl EntityBuilder for BossAsteroidBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let level = AsteroidSizeLevel::new(info.size_level);
+        let radius = level.typical_radius();
+        let max_health = level.max_health() * BOSS_HEALTH_MULTIPLIER;
+
+        // Boss is round, so armour plates cover whole outline
+        let mut asteroid = AsteroidCreateInfoBuilder::default();
+        asteroid
+            .position(info.position)
+            .size_level(info.size_level)
+            .base_velocity(info.base_velocity)
+            .material(AsteroidMaterial::Rock)
+            .shape(AsteroidShapeProfile {
+                edges: 18..24,
+                irregularity: 0.3,
+                spikiness: 0.1,
+                noise: None,
+            });
+
+        EntityBuilder::build(&asteroid, commands)
+            .remove::<DespawnOnOutOfRange>()
+            .insert(HealthBundle::new(max_health))
+            .insert(BossAsteroid::new(info.shed_period, info.guarded_goal))
+            .insert(BossArmored)
+            .insert(boss_armored_resistances());
+
+        let segment_radius = radius * 0.3;
+        let segment_health = max_health / info.segments.max(1) as f32;
+
+        for i in 0..info.segments {
+            let angle = 2.0 * PI * i as f32 / info.segments as f32;
+
+            let mut segment = ArmorSegmentBuilder::default();
+            segment
+                .position(Vec2::X.rotate_z(angle) * radius * 0.9)
+                .radius(segment_radius)
+                .health(segment_health);
+
+            commands.build_child_entity(&segment);
+        }
+
+        commands
+    }
+}
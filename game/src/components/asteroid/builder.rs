@@ -3,13 +3,18 @@ use bevy::prelude::*;
 use bevy_prototype_lyon::prelude::*;
 use bevy_rapier2d::prelude::*;
 use physic_objects::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
 
-use super::generate::generate_asteroid_vectors;
+use super::generate::{generate_asteroid_vectors, polygon_area};
 use super::level::AsteroidSizeLevel;
+use super::material::AsteroidMaterial;
+use super::shape::AsteroidShapeProfile;
 use super::Asteroid;
 use crate::components::common::{DespawnOnExitGame, DespawnOnOutOfRange, Layer, PositionBundle};
+use crate::components::faction::Faction;
 use crate::components::health::{CollisionDamageBundle, Health};
 use crate::entity::EntityBuilder;
+use crate::explosion::Explosive;
 use crate::random::Deviate;
 
 const ASTEROID_ANGULAR_SPEED_DEVIATION: f64 = 0.3;
@@ -24,6 +29,21 @@ pub struct AsteroidCreateInfo {
 
     #[builder(default = "Vec2::ZERO")]
     base_velocity: Vec2,
+    #[builder(default = "0.0")]
+    base_angular_velocity: f32,
+
+    /// Polygon area. Overrides typical size of level, used by fragments to split parent mass
+    #[builder(default, setter(strip_option))]
+    area: Option<f32>,
+
+    #[builder(default)]
+    material: AsteroidMaterial,
+    /// Shape profile. Overrides profile of material, e.g. for special sectors
+    #[builder(default, setter(strip_option))]
+    shape: Option<AsteroidShapeProfile>,
+    /// Seed of shape and motion. Same seed gives same asteroid
+    #[builder(default, setter(strip_option))]
+    seed: Option<u64>,
 }
 
 impl EntityBuilder for AsteroidCreateInfoBuilder {
@@ -32,25 +52,40 @@ impl EntityBuilder for AsteroidCreateInfoBuilder {
         commands: &'c mut EntityCommands<'w, 's, 'a>,
     ) -> &'c mut EntityCommands<'w, 's, 'a> {
         let create_info = self.build().unwrap();
-        let asteroid_structure = generate_asteroid_vectors();
+        let mut rng = match create_info.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let profile = create_info
+            .shape
+            .clone()
+            .unwrap_or_else(|| create_info.material.shape_profile());
+        let asteroid_structure = generate_asteroid_vectors(&profile, &mut rng);
         let asteroid_level = AsteroidSizeLevel::new(create_info.size_level);
-        let scale = asteroid_level.typical_radius();
+        let scale = match create_info.area {
+            Some(area) => (area / polygon_area(&asteroid_structure)).sqrt(),
+            None => asteroid_level.typical_radius(),
+        };
 
         let asteroid_structure: Vec<_> =
             asteroid_structure.into_iter().map(|v| v * scale).collect();
+        // Health depends on real size, so slivers are weaker than boulders of same level
+        let area = polygon_area(&asteroid_structure);
 
         let physic_object = TriangleFanBuilder::default()
             .params(PhysicObjectParams {
                 body: RigidBody::Dynamic,
-                mass_properties: ColliderMassProperties::Density(20.0),
+                mass_properties: ColliderMassProperties::Density(create_info.material.density()),
             })
             .points(asteroid_structure)
             .build();
 
         // Physic object
-        let mut rng = rand::thread_rng();
         commands.insert(physic_object).insert(Velocity {
-            angvel: 0.0_f32.deviate(&mut rng, ASTEROID_ANGULAR_SPEED_DEVIATION),
+            angvel: create_info
+                .base_angular_velocity
+                .deviate(&mut rng, ASTEROID_ANGULAR_SPEED_DEVIATION),
             linvel: create_info.base_velocity
                 + Vec2::ZERO.deviate(&mut rng, ASTEROID_LINEAR_SPEED_DEVIATION),
         });
@@ -58,7 +93,7 @@ impl EntityBuilder for AsteroidCreateInfoBuilder {
         // Set colors and style
         commands
             .insert(Fill {
-                color: Color::ORANGE_RED,
+                color: create_info.material.color(),
                 options: Default::default(),
             })
             .insert(Stroke {
@@ -66,14 +101,27 @@ impl EntityBuilder for AsteroidCreateInfoBuilder {
                 options: Default::default(),
             });
 
+        // Volatile asteroids blow up with power growing with size
+        if create_info.material == AsteroidMaterial::Volatile {
+            commands.insert(Explosive::new(
+                scale * 3.0,
+                50_000.0 * scale,
+                150.0 * asteroid_level.level() as f32,
+            ));
+        }
+
         let transform = PositionBundle::new(create_info.position, Layer::Main);
+        let max_health =
+            AsteroidSizeLevel::area_health(area) * create_info.material.health_multiplier();
 
         commands
             .insert(Asteroid)
-            .insert(Health::new(asteroid_level.max_health()))
+            .insert(create_info.material)
+            .insert(Faction::Environment)
+            .insert(Health::new(max_health))
             .insert(asteroid_level)
             .insert(transform)
-            .insert(CollisionDamageBundle::new())
+            .insert(CollisionDamageBundle::new(0.0, 10.0))
             .insert(DespawnOnOutOfRange)
             .insert(DespawnOnExitGame)
     }
@@ -5,35 +5,54 @@ use rand::distributions::Distribution;
 use rand::Rng;
 use statrs::distribution::Normal;
 
-const ASTEROID_IRREGULARITY: f64 = 1.0; // (0, 1]
-const ASTEROID_SPIKEYNES: f64 = 0.5; // (0, 1]
+use super::shape::{validate_polygon, AsteroidShapeProfile};
+
+/// Attempts to generate valid polygon before falling back to regular one
+const MAX_GENERATE_ATTEMPTS: usize = 10;
 
 /// Generates normalized polygon points that should be used to build collider of asteroid
-pub fn generate_asteroid_vectors() -> Vec<Vec2> {
-    let mut rng = rand::thread_rng();
+pub fn generate_asteroid_vectors<R: Rng>(profile: &AsteroidShapeProfile, rng: &mut R) -> Vec<Vec2> {
+    for _ in 0..MAX_GENERATE_ATTEMPTS {
+        let points = generate_polygon(profile, rng);
+
+        if validate_polygon(&points) {
+            return points;
+        }
+    }
 
-    let edges_count = rng.gen_range(8..15);
+    regular_polygon(profile.edges.start.max(3))
+}
+
+fn generate_polygon<R: Rng>(profile: &AsteroidShapeProfile, rng: &mut R) -> Vec<Vec2> {
+    let edges_count = rng.gen_range(profile.edges.clone());
     let mut out_points = Vec::with_capacity(edges_count);
 
     let mut current_angle = 0.0;
     let step = 2.0 * PI / (edges_count as f64);
 
     let angle_allow_disance = step / 2.0;
-    let angle_sigma = ASTEROID_IRREGULARITY * angle_allow_disance / 3.0; // 3.0 -- Rule of 3 sigma
+    let angle_sigma = profile.irregularity * angle_allow_disance / 3.0; // 3.0 -- Rule of 3 sigma
     let angle_distribution = Normal::new(0.0, angle_sigma).unwrap();
 
     let length_allow_distance = 0.9;
-    let length_distribution = Normal::new(1.0, ASTEROID_SPIKEYNES / 3.0).unwrap();
+    let length_distribution = Normal::new(1.0, profile.spikiness / 3.0).unwrap();
+
+    let noise = profile.noise.map(|noise| noise.sampler(rng));
 
     for _ in 0..edges_count {
-        let angle = angle_distribution.sample(&mut rng) + current_angle;
+        let angle = angle_distribution.sample(rng) + current_angle;
         let angle = angle.clamp(
             current_angle - angle_allow_disance,
             current_angle + angle_allow_disance,
         );
 
-        let length = length_distribution.sample(&mut rng);
-        let length = length.clamp(1.0 - length_allow_distance, 1.0 + length_allow_distance);
+        let length = length_distribution.sample(rng);
+        let mut length = length.clamp(1.0 - length_allow_distance, 1.0 + length_allow_distance);
+
+        if let Some(noise) = &noise {
+            let displacement = noise((angle / (2.0 * PI)) as f32) as f64;
+            length = (length * (1.0 + displacement)).max(1.0 - length_allow_distance);
+        }
 
         let vector = Quat::from_rotation_z(angle as f32)
             .mul_vec3(Vec3::Y)
@@ -47,3 +66,28 @@ pub fn generate_asteroid_vectors() -> Vec<Vec2> {
 
     out_points
 }
+
+fn regular_polygon(edges_count: usize) -> Vec<Vec2> {
+    let step = 2.0 * PI as f32 / edges_count as f32;
+
+    (0..edges_count)
+        .map(|i| {
+            Quat::from_rotation_z(step * i as f32)
+                .mul_vec3(Vec3::Y)
+                .truncate()
+        })
+        .collect()
+}
+
+/// Area of simple polygon by shoelace formula
+pub fn polygon_area(points: &[Vec2]) -> f32 {
+    let next_points = points.iter().cycle().skip(1);
+
+    let doubled_area: f32 = points
+        .iter()
+        .zip(next_points)
+        .map(|(current, next)| current.perp_dot(*next))
+        .sum();
+
+    doubled_area.abs() / 2.0
+}
@@ -1,5 +1,14 @@
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
 
+use super::material::AsteroidMaterial;
+
+/// Health of asteroid grows with square of its size level
+const HEALTH_PER_SQUARED_LEVEL: f32 = 100.0;
+/// Smallest size of asteroid measured by area, so slivers still have some health
+const MIN_AREA_LEVEL: f32 = 0.5;
+
 #[derive(Component)]
 pub struct AsteroidSizeLevel(i32);
 
@@ -17,7 +26,29 @@ impl AsteroidSizeLevel {
         (2.0 as f32).powi(self.level() + 3)
     }
 
+    /// Area of circle with typical radius
+    pub fn typical_area(&self) -> f32 {
+        PI * self.typical_radius().powi(2)
+    }
+
     pub fn max_health(&self) -> f32 {
-        100.0 * (self.level() as f32).powi(2)
+        HEALTH_PER_SQUARED_LEVEL * (self.level() as f32).powi(2)
+    }
+
+    /// Continuous size level of asteroid with polygon `area`.
+    /// Circle with typical radius of level has exactly this level
+    pub fn area_level(area: f32) -> f32 {
+        ((area / PI).sqrt().max(1.0).log2() - 3.0).max(MIN_AREA_LEVEL)
+    }
+
+    /// Continuous size level of asteroid with `mass`.
+    /// Rock asteroid of same mass has exactly this level, denser materials rank higher
+    pub fn mass_level(mass: f32) -> f32 {
+        Self::area_level(mass / AsteroidMaterial::Rock.density())
+    }
+
+    /// Health of asteroid with polygon `area`
+    pub fn area_health(area: f32) -> f32 {
+        HEALTH_PER_SQUARED_LEVEL * Self::area_level(area).powi(2)
     }
 }
@@ -0,0 +1,142 @@
+use std::ops::RangeInclusive;
+
+use bevy::prelude::*;
+
+use super::shape::{AsteroidShapeProfile, ShapeNoise};
+use crate::random::WeightedTable;
+
+/// What asteroid is made of. Defines its physics, look and what happens when it is destroyed
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AsteroidMaterial {
+    #[default]
+    Rock,
+    /// Light and fragile, shatters into many small pieces
+    Ice,
+    /// Heavy and tough, drops resources
+    Metal,
+    Crystal,
+    /// Explodes when destroyed
+    Volatile,
+}
+
+impl AsteroidMaterial {
+    pub fn density(&self) -> f32 {
+        match self {
+            Self::Rock => 20.0,
+            Self::Ice => 9.0,
+            Self::Metal => 45.0,
+            Self::Crystal => 25.0,
+            Self::Volatile => 15.0,
+        }
+    }
+
+    /// Multiplier of [AsteroidSizeLevel](super::AsteroidSizeLevel) max health
+    pub fn health_multiplier(&self) -> f32 {
+        match self {
+            Self::Rock => 1.0,
+            Self::Ice => 0.5,
+            Self::Metal => 2.5,
+            Self::Crystal => 1.5,
+            Self::Volatile => 0.6,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Rock => Color::ORANGE_RED,
+            Self::Ice => Color::rgb(0.7, 0.9, 1.0),
+            Self::Metal => Color::SILVER,
+            Self::Crystal => Color::rgb(0.7, 0.3, 1.0),
+            Self::Volatile => Color::rgb(0.9, 0.9, 0.1),
+        }
+    }
+
+    /// Possible count of shards created on death
+    pub fn split_count(&self) -> RangeInclusive<usize> {
+        match self {
+            Self::Rock => 2..=4,
+            Self::Ice => 5..=8,
+            Self::Metal => 2..=3,
+            Self::Crystal => 3..=5,
+            Self::Volatile => 2..=3,
+        }
+    }
+
+    /// Size level of shards. Ice shatters into smallest pieces
+    pub fn shard_level(&self, level: i32) -> i32 {
+        match self {
+            Self::Ice => (level - 2).max(1),
+            _ => level - 1,
+        }
+    }
+
+    /// Material of shards. Exploded volatile core leaves plain rock
+    pub fn shard_material(&self) -> Self {
+        match self {
+            Self::Volatile => Self::Rock,
+            material => *material,
+        }
+    }
+
+    /// Count of resource fragments dropped on death
+    pub fn resource_drop(&self, level: i32) -> u32 {
+        match self {
+            Self::Metal => level.max(1) as u32,
+            _ => 0,
+        }
+    }
+
+    /// Shape of asteroids made of material
+    pub fn shape_profile(&self) -> AsteroidShapeProfile {
+        match self {
+            Self::Rock => AsteroidShapeProfile::default(),
+            // Sharp shards
+            Self::Ice => AsteroidShapeProfile {
+                edges: 6..10,
+                irregularity: 0.8,
+                spikiness: 0.8,
+                noise: None,
+            },
+            // Smooth and round
+            Self::Metal => AsteroidShapeProfile {
+                edges: 12..18,
+                irregularity: 0.3,
+                spikiness: 0.2,
+                noise: Some(ShapeNoise {
+                    frequency: 3,
+                    amplitude: 0.1,
+                    octaves: 1,
+                }),
+            },
+            // Few flat faces
+            Self::Crystal => AsteroidShapeProfile {
+                edges: 5..8,
+                irregularity: 0.4,
+                spikiness: 0.4,
+                noise: None,
+            },
+            // Lumpy
+            Self::Volatile => AsteroidShapeProfile {
+                edges: 14..20,
+                irregularity: 0.6,
+                spikiness: 0.3,
+                noise: Some(ShapeNoise {
+                    frequency: 4,
+                    amplitude: 0.25,
+                    octaves: 2,
+                }),
+            },
+        }
+    }
+
+    /// Spawn table used for new asteroids in open space
+    pub fn spawn_table() -> WeightedTable<Self> {
+        WeightedTable::new(vec![
+            (Self::Rock, 60.0),
+            (Self::Ice, 15.0),
+            (Self::Metal, 10.0),
+            (Self::Crystal, 10.0),
+            (Self::Volatile, 5.0),
+        ])
+    }
+}
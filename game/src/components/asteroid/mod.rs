@@ -2,11 +2,24 @@ use bevy::prelude::*;
 
 /// Generates asteroid
 mod generate;
+pub use generate::generate_asteroid_vectors;
+
+/// Asteroid shape parameters and validation
+mod shape;
+pub use shape::{shapes_svg, validate_polygon, AsteroidShapeProfile, ShapeNoise};
 
 /// Build asteroid
 mod builder;
 pub use builder::AsteroidCreateInfoBuilder as AsteroidBuilder;
 
+/// Boss asteroids with armour segments
+mod boss;
+pub use boss::{ArmorSegment, BossArmored, BossAsteroid, BossAsteroidBuilder};
+
+/// Asteroid materials
+mod material;
+pub use material::AsteroidMaterial;
+
 /// Asteroid levels descriptors
 mod level;
 pub use level::AsteroidSizeLevel;
@@ -0,0 +1,207 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Smallest allowed distance from center to vertex for normalized shape
+const MIN_VERTEX_LENGTH: f32 = 0.05;
+
+/// Parameters of procedural asteroid polygon
+#[derive(Clone, Debug)]
+pub struct AsteroidShapeProfile {
+    /// Possible count of polygon edges
+    pub edges: Range<usize>,
+    /// How far vertices deviate from even angular spacing, (0, 1]
+    pub irregularity: f64,
+    /// How far vertices deviate from unit radius, (0, 1]
+    pub spikiness: f64,
+    /// Smooth displacement of vertices along the outline
+    pub noise: Option<ShapeNoise>,
+}
+
+impl Default for AsteroidShapeProfile {
+    fn default() -> Self {
+        Self {
+            edges: 8..15,
+            irregularity: 1.0,
+            spikiness: 0.5,
+            noise: None,
+        }
+    }
+}
+
+/// Periodic value noise applied around polygon outline
+#[derive(Clone, Copy, Debug)]
+pub struct ShapeNoise {
+    /// Count of noise cells around outline in first octave
+    pub frequency: usize,
+    /// Relative radius displacement of first octave
+    pub amplitude: f32,
+    /// Every next octave has doubled frequency and halved amplitude
+    pub octaves: u32,
+}
+
+impl ShapeNoise {
+    /// Generates noise function of position `t` in [0, 1) around outline
+    pub fn sampler<R: Rng>(&self, rng: &mut R) -> impl Fn(f32) -> f32 {
+        let octaves: Vec<(Vec<f32>, f32)> = (0..self.octaves)
+            .map(|octave| {
+                let cells = self.frequency.max(1) << octave;
+                let values = (0..cells).map(|_| rng.gen_range(-1.0..1.0)).collect();
+                (values, self.amplitude / (1 << octave) as f32)
+            })
+            .collect();
+
+        move |t| {
+            octaves
+                .iter()
+                .map(|(values, amplitude)| value_noise(values, t) * amplitude)
+                .sum()
+        }
+    }
+}
+
+/// Smoothly interpolated periodic lattice values
+fn value_noise(values: &[f32], t: f32) -> f32 {
+    let position = t.rem_euclid(1.0) * values.len() as f32;
+    let index = position.floor() as usize % values.len();
+    let next = (index + 1) % values.len();
+
+    let fraction = position.fract();
+    let smooth = fraction * fraction * (3.0 - 2.0 * fraction);
+
+    values[index] + (values[next] - values[index]) * smooth
+}
+
+/// Returns true if polygon can be used for asteroid: it has no self intersections
+/// and its vertices go counterclockwise around origin, so triangle fan from origin covers it
+pub fn validate_polygon(points: &[Vec2]) -> bool {
+    if points.len() < 3 {
+        return false;
+    }
+
+    if points
+        .iter()
+        .any(|point| point.length() < MIN_VERTEX_LENGTH)
+    {
+        return false;
+    }
+
+    // Star-shaped around origin
+    let edges = || points.iter().zip(points.iter().cycle().skip(1));
+    if edges().any(|(current, next)| current.perp_dot(*next) <= 0.0) {
+        return false;
+    }
+
+    // Simple: non adjacent edges do not intersect
+    let count = points.len();
+    for i in 0..count {
+        for j in (i + 2)..count {
+            if i == 0 && j == count - 1 {
+                continue;
+            }
+
+            let first = (points[i], points[(i + 1) % count]);
+            let second = (points[j], points[(j + 1) % count]);
+
+            if segments_intersect(first, second) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+fn segments_intersect((a1, a2): (Vec2, Vec2), (b1, b2): (Vec2, Vec2)) -> bool {
+    let side = |origin: Vec2, end: Vec2, point: Vec2| (end - origin).perp_dot(point - origin);
+
+    let a_sides = side(a1, a2, b1) * side(a1, a2, b2);
+    let b_sides = side(b1, b2, a1) * side(b1, b2, a2);
+
+    a_sides <= 0.0 && b_sides <= 0.0
+}
+
+/// Renders polygons on grid to SVG document. Used to preview shape profiles
+pub fn shapes_svg(shapes: &[Vec<Vec2>], columns: usize) -> String {
+    const CELL: f32 = 120.0;
+    // Normalized shapes fit into radius 2
+    const SCALE: f32 = CELL / 4.0;
+
+    let columns = columns.max(1);
+    let rows = shapes.len().div_ceil(columns);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        CELL * columns as f32,
+        CELL * rows as f32
+    );
+
+    for (index, shape) in shapes.iter().enumerate() {
+        let center = Vec2::new(
+            (index % columns) as f32 + 0.5,
+            (index / columns) as f32 + 0.5,
+        ) * CELL;
+
+        let points: Vec<String> = shape
+            .iter()
+            .map(|point| {
+                // SVG y axis points down
+                let point = center + Vec2::new(point.x, -point.y) * SCALE;
+                format!("{:.2},{:.2}", point.x, point.y)
+            })
+            .collect();
+
+        svg += &format!(
+            "  <polygon points=\"{}\" fill=\"orangered\" stroke=\"black\"/>\n",
+            points.join(" ")
+        );
+    }
+
+    svg += "</svg>\n";
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(angles_deg: &[f32], length: f32) -> Vec<Vec2> {
+        angles_deg
+            .iter()
+            .map(|angle| Vec2::from_angle(angle.to_radians()) * length)
+            .collect()
+    }
+
+    #[test]
+    fn counterclockwise_polygon_is_valid() {
+        assert!(validate_polygon(&polygon(&[0.0, 90.0, 180.0, 270.0], 1.0)));
+        assert!(validate_polygon(&[
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.3, 0.3),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-1.0, 0.2),
+            Vec2::new(0.0, -1.0),
+        ]));
+    }
+
+    #[test]
+    fn clockwise_polygon_is_invalid() {
+        assert!(!validate_polygon(&polygon(&[270.0, 180.0, 90.0, 0.0], 1.0)));
+    }
+
+    #[test]
+    fn self_intersecting_polygon_is_invalid() {
+        // Pentagram turns counterclockwise at every vertex, but goes around origin twice
+        assert!(!validate_polygon(&polygon(
+            &[0.0, 144.0, 288.0, 72.0, 216.0],
+            1.0
+        )));
+    }
+
+    #[test]
+    fn degenerate_polygon_is_invalid() {
+        assert!(!validate_polygon(&polygon(&[0.0, 120.0], 1.0)));
+        assert!(!validate_polygon(&polygon(&[0.0, 120.0, 240.0], 0.01)));
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::AdditionalMassProperties;
+
+/// Mass of one resource unit in hold
+const CARGO_UNIT_MASS: f32 = 300.0;
+
+/// Ship hold for collected resources. Loaded resources make ship heavier
+#[derive(Component, Clone)]
+pub struct Cargo {
+    amount: u32,
+    capacity: u32,
+}
+
+impl Cargo {
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            amount: 0,
+            capacity,
+        }
+    }
+
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn free_space(&self) -> u32 {
+        self.capacity - self.amount
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.free_space() == 0
+    }
+
+    /// Loads as much of `amount` as fits and returns loaded part
+    pub fn load(&mut self, amount: u32) -> u32 {
+        let loaded = amount.min(self.free_space());
+        self.amount += loaded;
+        loaded
+    }
+
+    /// Empties hold and returns unloaded amount
+    pub fn unload(&mut self) -> u32 {
+        std::mem::take(&mut self.amount)
+    }
+
+    /// Extra mass of loaded resources
+    pub fn mass(&self) -> f32 {
+        self.amount as f32 * CARGO_UNIT_MASS
+    }
+}
+
+#[derive(Bundle, Clone)]
+pub struct CargoBundle {
+    cargo: Cargo,
+    mass: AdditionalMassProperties,
+}
+
+impl CargoBundle {
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            cargo: Cargo::new(capacity),
+            mass: AdditionalMassProperties::Mass(0.0),
+        }
+    }
+}
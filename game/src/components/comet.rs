@@ -0,0 +1,92 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+use bevy_rapier2d::prelude::*;
+use physic_objects::prelude::*;
+
+use crate::components::common::{
+    Active, DespawnOnExitGame, Layer, PositionBundle, TimeToLiveBundle,
+};
+use crate::components::faction::Faction;
+use crate::components::health::{CollisionDamageBundle, HealthBundle};
+use crate::components::particle::comet::CometTailParticleGeneratorBundle;
+use crate::entity::EntityBuilder;
+
+/// Comet mark
+#[derive(Component)]
+pub struct Comet;
+
+/// Screen edge warning about approaching comet
+#[derive(Component)]
+pub struct CometWarning(Entity);
+
+impl CometWarning {
+    pub fn new(comet: Entity) -> Self {
+        Self(comet)
+    }
+
+    pub fn comet(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Comet flies straight with constant velocity and disappears after `time_to_live`
+#[derive(Builder)]
+pub struct CometCreateInfo {
+    position: Vec2,
+    velocity: Vec2,
+    #[builder(default = "40.0")]
+    radius: f32,
+    #[builder(default = "30.0")]
+    time_to_live: f32,
+}
+
+pub use CometCreateInfoBuilder as CometBuilder;
+
+impl EntityBuilder for CometBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let physic_object = CircleBuilder::default()
+            .params(PhysicObjectParams {
+                body: RigidBody::Dynamic,
+                mass_properties: ColliderMassProperties::Density(30.0),
+            })
+            .radius(info.radius)
+            .build();
+
+        // Comet looks along its path, so tail generator points backward
+        let position = PositionBundle::new(info.position, Layer::Main)
+            .with_angle(Vec2::X.angle_between(info.velocity));
+
+        commands
+            .insert(physic_object)
+            .insert(Velocity::linear(info.velocity))
+            .insert(LockedAxes::ROTATION_LOCKED)
+            .insert(Fill {
+                color: Color::rgb(0.8, 0.95, 1.0),
+                options: default(),
+            })
+            .insert(Stroke {
+                color: Color::WHITE,
+                options: default(),
+            })
+            .insert(Comet)
+            .insert(Faction::Environment)
+            .insert(HealthBundle::new(20000.0))
+            .insert(CollisionDamageBundle::new(200.0, 100.0))
+            .insert(position)
+            .insert(TimeToLiveBundle::new(info.time_to_live))
+            .insert(DespawnOnExitGame)
+            .with_children(|cb| {
+                cb.spawn(CometTailParticleGeneratorBundle::new(
+                    info.velocity.length(),
+                    3.0,
+                    info.radius as f64 / 3.0,
+                ))
+                .insert(Active);
+            })
+    }
+}
@@ -120,4 +120,10 @@ impl PositionBundle {
             layer,
         }
     }
+
+    /// Rotates position around Z axis by `angle` in radians
+    pub fn with_angle(mut self, angle: f32) -> Self {
+        self.transform.local.rotate_z(angle);
+        self
+    }
 }
@@ -14,6 +14,8 @@ pub use linear::SwayEngineBuilder;
 #[derive(Component)]
 pub struct Engine {
     max_force: ExternalForce,
+    /// Temporary boost of max force, e.g. by power-up
+    force_multiplier: f32,
     throttle: f32,
     min_throttle: f32,
     max_throttle: f32,
@@ -23,6 +25,7 @@ impl Engine {
     pub fn new(max_force: ExternalForce) -> Self {
         Self {
             max_force,
+            force_multiplier: 1.0,
             throttle: 0.0,
             min_throttle: -1.0,
             max_throttle: 1.0,
@@ -48,12 +51,18 @@ impl Engine {
 
     /// Returns force applied by engine
     pub fn force(&self) -> ExternalForce {
+        let throttle = self.throttle * self.force_multiplier;
+
         ExternalForce {
-            force: self.max_force.force * self.throttle,
-            torque: self.max_force.torque * self.throttle,
+            force: self.max_force.force * throttle,
+            torque: self.max_force.torque * throttle,
         }
     }
 
+    pub fn set_force_multiplier(&mut self, multiplier: f32) {
+        self.force_multiplier = multiplier;
+    }
+
     /// Sets throttle
     pub fn set_throttle(&mut self, throttle: f32) {
         self.throttle = (throttle).clamp(self.min_throttle, self.max_throttle);
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+/// Side entity belongs to. Used to filter friendly fire and find enemies
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Faction {
+    Player,
+    Hostile,
+    /// Asteroids and other world objects
+    Environment,
+}
+
+/// Entity which fired the projectile. Damage dealt by projectile is credited to it
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ProjectileOwner(Entity);
+
+impl ProjectileOwner {
+    pub fn new(owner: Entity) -> Self {
+        Self(owner)
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Friendly fire rules
+#[derive(Resource, Default)]
+pub struct FriendlyFire(pub bool);
+
+impl FriendlyFire {
+    /// Returns true if `attacker` can damage `target`. Entities without faction can damage anything
+    pub fn allows(&self, attacker: Option<&Faction>, target: Option<&Faction>) -> bool {
+        match (attacker, target) {
+            (Some(attacker), Some(target)) => self.0 || attacker != target,
+            _ => true,
+        }
+    }
+}
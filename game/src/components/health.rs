@@ -1,12 +1,33 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use bevy::prelude::*;
-use bevy_rapier2d::prelude::{ActiveEvents, ContactForceEventThreshold};
+use bevy_rapier2d::prelude::{ActiveEvents, ReadMassProperties};
+
+use crate::damage::{DamageKind, Hit};
 
 /// Mark that component is dead
 #[derive(Component)]
 pub struct Dead;
 
+/// Impact that killed entity. Used to break dead entity apart in direction of the blow
+#[derive(Component, Clone, Copy, Debug)]
+pub struct FatalHit(Hit);
+
+impl FatalHit {
+    pub fn new(hit: Hit) -> Self {
+        Self(hit)
+    }
+
+    pub fn point(&self) -> Vec2 {
+        self.0.point
+    }
+
+    pub fn direction(&self) -> Vec2 {
+        self.0.direction
+    }
+}
+
 /// Health component
 #[derive(Component, Default)]
 pub struct Health(f32);
@@ -26,13 +47,16 @@ impl Health {
         0.0 < self.health()
     }
 
+    /// Health never drops below zero
     #[inline(always)]
     pub fn damage(&mut self, damage: f32) {
-        if self.health() < 0.0 {
-            return;
-        }
+        self.0 = (self.0 - damage.max(0.0)).max(0.0);
+    }
 
-        self.0 -= damage;
+    /// Health never exceeds `max_health`
+    #[inline(always)]
+    pub fn heal(&mut self, amount: f32, max_health: f32) {
+        self.0 = (self.0 + amount.max(0.0)).min(max_health);
     }
 }
 
@@ -50,6 +74,69 @@ impl MaxHealth {
     }
 }
 
+/// Slowly restores [Health] when entity is not damaged for a while
+#[derive(Component, Clone)]
+pub struct HealthRegeneration {
+    /// Health per second
+    rate: f32,
+    /// Time in seconds after last damage before regeneration starts
+    delay: f32,
+    /// Regeneration stops at this fraction of [MaxHealth]
+    cap: f32,
+    since_damage: f32,
+    last_health: f32,
+}
+
+impl HealthRegeneration {
+    pub fn new(rate: f32, delay: f32, cap: f32) -> Self {
+        Self {
+            rate,
+            delay,
+            cap: cap.clamp(0.0, 1.0),
+            since_damage: 0.0,
+            last_health: 0.0,
+        }
+    }
+
+    /// Returns health to restore this update
+    pub fn update(&mut self, time: f32, health: f32, max_health: f32) -> f32 {
+        if health < self.last_health {
+            self.since_damage = 0.0;
+        }
+
+        self.last_health = health;
+        self.since_damage += time;
+
+        if self.since_damage < self.delay {
+            return 0.0;
+        }
+
+        let limit = self.cap * max_health - health;
+        (self.rate * time).min(limit).max(0.0)
+    }
+}
+
+/// Part of damage of specified kind entity ignores. 1.0 makes entity immune to it
+#[derive(Component, Default, Clone)]
+pub struct Resistances(HashMap<DamageKind, f32>);
+
+impl Resistances {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, kind: DamageKind, resistance: f32) -> Self {
+        self.0.insert(kind, resistance.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Returns damage left after resistance
+    pub fn reduce(&self, kind: DamageKind, damage: f32) -> f32 {
+        let resistance = self.0.get(&kind).copied().unwrap_or_default();
+        damage * (1.0 - resistance)
+    }
+}
+
 /// Tells entity with health fully regenerate on next update
 #[derive(Component)]
 pub struct RegenerateOneTimeToFull;
@@ -74,19 +161,78 @@ impl HealthBundle {
 #[derive(Component)]
 pub struct CollisionDamage;
 
+/// Flat damage absorbed from each collision
+#[derive(Component, Clone, Copy)]
+pub struct Armor {
+    armor: f32,
+    /// Armor lost by effects like corrosion
+    reduction: f32,
+}
+
+impl Armor {
+    pub fn new(armor: f32) -> Self {
+        Self {
+            armor,
+            reduction: 0.0,
+        }
+    }
+
+    pub fn armor(&self) -> f32 {
+        (self.armor - self.reduction).max(0.0)
+    }
+
+    pub fn set_reduction(&mut self, reduction: f32) {
+        self.reduction = reduction;
+    }
+}
+
+/// Collisions dealing less damage are ignored
+#[derive(Component, Clone, Copy)]
+pub struct DamageThreshold(f32);
+
+impl DamageThreshold {
+    pub fn new(threshold: f32) -> Self {
+        Self(threshold)
+    }
+
+    pub fn threshold(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Linear velocity before physics step. Velocity after step is already changed by collision
+#[derive(Component, Default, Clone, Copy)]
+pub struct PreStepVelocity(Vec2);
+
+impl PreStepVelocity {
+    pub fn new(velocity: Vec2) -> Self {
+        Self(velocity)
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        self.0
+    }
+}
+
 #[derive(Bundle)]
 pub struct CollisionDamageBundle {
     mark: CollisionDamage,
     events: ActiveEvents,
-    threshold: ContactForceEventThreshold,
+    mass: ReadMassProperties,
+    velocity: PreStepVelocity,
+    armor: Armor,
+    threshold: DamageThreshold,
 }
 
 impl CollisionDamageBundle {
-    pub fn new() -> Self {
+    pub fn new(armor: f32, threshold: f32) -> Self {
         Self {
             mark: CollisionDamage,
-            events: ActiveEvents::CONTACT_FORCE_EVENTS,
-            threshold: ContactForceEventThreshold(100_000.0),
+            events: ActiveEvents::COLLISION_EVENTS,
+            mass: ReadMassProperties::default(),
+            velocity: PreStepVelocity::default(),
+            armor: Armor::new(armor),
+            threshold: DamageThreshold::new(threshold),
         }
     }
 }
@@ -1,11 +1,19 @@
 pub mod asteroid;
 pub mod camera;
+pub mod cargo;
+pub mod comet;
 pub mod common;
 pub mod engine;
+pub mod faction;
 pub mod health;
 pub mod movement;
 pub mod particle;
+pub mod pickup;
 pub mod player;
+pub mod repair;
+pub mod resource;
+pub mod shield;
 pub mod ship;
+pub mod status;
 pub mod ui;
 pub mod weapon;
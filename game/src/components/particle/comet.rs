@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{components::common::TimeToLiveBundle, math::RotateAroundZ, random::Deviate};
+
+use super::{
+    ParticleBundle, ParticleBundleBuilder, ParticleColor, ParticleGenerator,
+    ParticleGeneratorBundle, ParticleSize, ParticleVelocity,
+};
+
+pub struct CometTailParticleBuilder {
+    /// Speed of comet. Particles compensate it to hang in space behind comet
+    speed: f32,
+    variance: f64,
+}
+
+impl CometTailParticleBuilder {
+    pub fn new(speed: f32) -> Self {
+        Self {
+            speed,
+            variance: 0.05,
+        }
+    }
+}
+
+impl ParticleBundleBuilder for CometTailParticleBuilder {
+    fn build(&self) -> ParticleBundle {
+        let mut rng = rand::thread_rng();
+
+        let angle = 0.0_f32.deviate(&mut rng, self.variance);
+        let velocity = (Vec2::NEG_X * self.speed * 0.95).rotate_z(angle);
+
+        ParticleBundle {
+            size: ParticleSize::new(14.0, 2.0),
+            color: ParticleColor::new(
+                Color::hsla(190.0, 0.6, 0.9, 0.8),
+                Color::hsla(220.0, 0.8, 0.4, 0.0),
+            ),
+            velocity: ParticleVelocity::new(velocity, velocity),
+            time_to_live: TimeToLiveBundle::new(2.5),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct CometTailParticleGeneratorBundle {
+    base_bundle: ParticleGeneratorBundle,
+}
+
+impl CometTailParticleGeneratorBundle {
+    pub fn new(speed: f32, rate: f64, deviation: f64) -> Self {
+        let builder = CometTailParticleBuilder::new(speed);
+        let generator = ParticleGenerator::new(builder);
+
+        Self {
+            base_bundle: ParticleGeneratorBundle::new(
+                generator,
+                rate,
+                deviation,
+                Transform::default(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    components::common::{DespawnOnExitGame, TimeToLiveBundle},
+    math::RotateAroundZ,
+};
+
+use super::{
+    ParticleBundle, ParticleBundleBuilder, ParticleBurst, ParticleColor, ParticleGenerator,
+    ParticleGeneratorDeviation, ParticleSize, ParticleVelocity,
+};
+
+pub struct ExplosionParticleBuilder {
+    speed: f32,
+}
+
+impl ExplosionParticleBuilder {
+    pub fn new(speed: f32) -> Self {
+        Self { speed }
+    }
+}
+
+impl ParticleBundleBuilder for ExplosionParticleBuilder {
+    fn build(&self) -> ParticleBundle {
+        let mut rng = rand::thread_rng();
+
+        let angle = rng.gen_range(0.0..(2.0 * PI));
+        let speed = rng.gen_range(0.25..1.0) * self.speed;
+        let start_velocity = Vec2::X.rotate_z(angle) * speed;
+
+        ParticleBundle {
+            size: ParticleSize::new(3.0, 12.0),
+            color: ParticleColor::new(Color::hsl(50.0, 1.0, 0.9), Color::hsl(10.0, 1.0, 0.3)),
+            velocity: ParticleVelocity::new(start_velocity, start_velocity * 0.1),
+            time_to_live: TimeToLiveBundle::new(0.6),
+        }
+    }
+}
+
+/// One shot particle generator of explosion
+#[derive(Bundle)]
+pub struct ExplosionBurstBundle {
+    generator: ParticleGenerator,
+    burst: ParticleBurst,
+    deviation: ParticleGeneratorDeviation,
+    transform: TransformBundle,
+    despawn_mark: DespawnOnExitGame,
+}
+
+impl ExplosionBurstBundle {
+    pub fn new(position: Vec2, radius: f32) -> Self {
+        let builder = ExplosionParticleBuilder::new(radius * 2.0);
+        let generator = ParticleGenerator::new(builder);
+
+        Self {
+            generator,
+            burst: ParticleBurst::new((radius / 4.0) as usize),
+            deviation: ((radius / 6.0) as f64).into(),
+            transform: TransformBundle::from(Transform::from_translation(position.extend(0.0))),
+            despawn_mark: DespawnOnExitGame,
+        }
+    }
+}
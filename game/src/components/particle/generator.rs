@@ -48,6 +48,21 @@ impl Into<f64> for ParticleGeneratorRate {
     }
 }
 
+/// Count of particles generated at once by generator without parent.
+/// Generator is despawned after burst
+#[derive(Component)]
+pub struct ParticleBurst(usize);
+
+impl ParticleBurst {
+    pub fn new(count: usize) -> Self {
+        Self(count)
+    }
+
+    pub fn count(&self) -> usize {
+        self.0
+    }
+}
+
 /// Deviation from center of spawn position
 #[derive(Component)]
 pub struct ParticleGeneratorDeviation(f64);
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    components::common::{DespawnOnExitGame, TimeToLiveBundle},
+    math::RotateAroundZ,
+};
+
+use super::{
+    ParticleBundle, ParticleBundleBuilder, ParticleBurst, ParticleColor, ParticleGenerator,
+    ParticleGeneratorDeviation, ParticleSize, ParticleVelocity,
+};
+
+/// Max angle between impact direction and particle velocity in radians
+const IMPACT_SPREAD: f32 = 1.0;
+/// Particles count in one impact
+const IMPACT_PARTICLES: usize = 6;
+
+pub struct ImpactParticleBuilder {
+    direction: Vec2,
+}
+
+impl ImpactParticleBuilder {
+    pub fn new(direction: Vec2) -> Self {
+        Self { direction }
+    }
+}
+
+impl ParticleBundleBuilder for ImpactParticleBuilder {
+    fn build(&self) -> ParticleBundle {
+        let mut rng = rand::thread_rng();
+
+        let angle = rng.gen_range(-IMPACT_SPREAD..IMPACT_SPREAD);
+        let speed = rng.gen_range(100.0..300.0);
+        let start_velocity = self.direction.rotate_z(angle) * speed;
+
+        ParticleBundle {
+            size: ParticleSize::new(2.0, 0.5),
+            color: ParticleColor::new(Color::hsl(45.0, 1.0, 0.8), Color::hsl(20.0, 1.0, 0.4)),
+            velocity: ParticleVelocity::new(start_velocity, start_velocity * 0.2),
+            time_to_live: TimeToLiveBundle::new(0.25),
+        }
+    }
+}
+
+/// One shot particle generator of projectile hit. Sparks fly in `direction`
+#[derive(Bundle)]
+pub struct ImpactBurstBundle {
+    generator: ParticleGenerator,
+    burst: ParticleBurst,
+    deviation: ParticleGeneratorDeviation,
+    transform: TransformBundle,
+    despawn_mark: DespawnOnExitGame,
+}
+
+impl ImpactBurstBundle {
+    pub fn new(position: Vec2, direction: Vec2) -> Self {
+        let builder = ImpactParticleBuilder::new(direction.normalize_or_zero());
+        let generator = ParticleGenerator::new(builder);
+
+        Self {
+            generator,
+            burst: ParticleBurst::new(IMPACT_PARTICLES),
+            deviation: 1.0_f64.into(),
+            transform: TransformBundle::from(Transform::from_translation(position.extend(0.0))),
+            despawn_mark: DespawnOnExitGame,
+        }
+    }
+}
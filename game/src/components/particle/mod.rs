@@ -4,6 +4,7 @@ mod mathcolor;
 use mathcolor::MathColor;
 
 mod generator;
+pub use generator::ParticleBurst;
 pub use generator::ParticleGenerator;
 pub use generator::ParticleGeneratorBundle;
 pub use generator::ParticleGeneratorDeviation;
@@ -17,6 +18,15 @@ pub mod fire;
 /// Propulsion(Engine) particles
 pub mod propulsion;
 
+/// Explosion particles
+pub mod explosion;
+
+/// Projectile hit sparks
+pub mod impact;
+
+/// Comet tail particles
+pub mod comet;
+
 #[derive(Component, Clone, Copy)]
 pub struct ParticleSize {
     start: f32,
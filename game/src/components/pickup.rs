@@ -0,0 +1,220 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::{prelude::*, shapes};
+use bevy_rapier2d::prelude::*;
+
+use crate::components::common::{DespawnOnExitGame, Layer, PositionBundle, TimeToLiveBundle};
+use crate::entity::EntityBuilder;
+use crate::random::WeightedTable;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PickupKind {
+    /// Restores health
+    Repair,
+    /// Raises weapons firerate
+    RapidFire,
+    /// Boosts engines force
+    Overdrive,
+    /// Recharges shield
+    Shield,
+    /// Raises score multiplier
+    ScoreMultiplier,
+}
+
+impl PickupKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PickupKind::Repair => "Repair",
+            PickupKind::RapidFire => "Rapid fire",
+            PickupKind::Overdrive => "Overdrive",
+            PickupKind::Shield => "Shield",
+            PickupKind::ScoreMultiplier => "Score x2",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            PickupKind::Repair => Color::LIME_GREEN,
+            PickupKind::RapidFire => Color::ORANGE,
+            PickupKind::Overdrive => Color::AZURE,
+            PickupKind::Shield => Color::CYAN,
+            PickupKind::ScoreMultiplier => Color::GOLD,
+        }
+    }
+
+    /// Time in seconds power-up lasts. None for instant pickups
+    pub fn duration(&self) -> Option<f32> {
+        match self {
+            PickupKind::Repair | PickupKind::Shield => None,
+            PickupKind::RapidFire => Some(10.0),
+            PickupKind::Overdrive => Some(10.0),
+            PickupKind::ScoreMultiplier => Some(15.0),
+        }
+    }
+
+    /// Drop table of asteroid of `size_level`. Bigger asteroids drop more often
+    pub fn drop_table(size_level: i32) -> WeightedTable<Option<Self>> {
+        let nothing = match size_level {
+            ..=1 => 60.0,
+            2 => 30.0,
+            3 => 15.0,
+            _ => 5.0,
+        };
+
+        WeightedTable::new(vec![
+            (None, nothing),
+            (Some(Self::Repair), 1.0),
+            (Some(Self::RapidFire), 1.0),
+            (Some(Self::Overdrive), 0.8),
+            (Some(Self::Shield), 0.8),
+            (Some(Self::ScoreMultiplier), 0.4),
+        ])
+    }
+
+    /// Table of guaranteed rewards, e.g. for beaten boss
+    pub fn reward_table() -> WeightedTable<Self> {
+        WeightedTable::new(vec![
+            (Self::Repair, 1.0),
+            (Self::RapidFire, 1.0),
+            (Self::Overdrive, 1.0),
+            (Self::Shield, 1.0),
+            (Self::ScoreMultiplier, 1.0),
+        ])
+    }
+}
+
+/// Power-up that can be collected by player
+#[derive(Component)]
+pub struct Pickup(PickupKind);
+
+impl Pickup {
+    pub fn kind(&self) -> PickupKind {
+        self.0
+    }
+}
+
+/// Pickup blinks when this time in seconds is left to live
+#[derive(Component)]
+pub struct BlinkBeforeDespawn(f32);
+
+impl BlinkBeforeDespawn {
+    pub fn time(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Timed power-up active on entity
+#[derive(Clone, Copy, Debug)]
+pub struct PowerUp {
+    kind: PickupKind,
+    /// Time left in seconds
+    duration: f32,
+}
+
+impl PowerUp {
+    pub fn kind(&self) -> PickupKind {
+        self.kind
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+}
+
+/// Active timed power-ups of entity
+#[derive(Component, Default)]
+pub struct PowerUps(Vec<PowerUp>);
+
+impl PowerUps {
+    /// Starts power-up or refreshes its duration
+    pub fn apply(&mut self, kind: PickupKind, duration: f32) {
+        match self.0.iter_mut().find(|p| p.kind == kind) {
+            Some(existing) => existing.duration = existing.duration.max(duration),
+            None => self.0.push(PowerUp { kind, duration }),
+        }
+    }
+
+    pub fn has(&self, kind: PickupKind) -> bool {
+        self.0.iter().any(|p| p.kind == kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PowerUp> {
+        self.0.iter()
+    }
+
+    /// Updates durations and returns true if some power-up expired
+    pub fn update(&mut self, time: f32) -> bool {
+        let count = self.0.len();
+
+        for power_up in self.0.iter_mut() {
+            power_up.duration -= time;
+        }
+        self.0.retain(|p| 0.0 < p.duration);
+
+        self.0.len() != count
+    }
+}
+
+#[derive(Builder)]
+pub struct PickupCreateInfo {
+    position: Vec2,
+    kind: PickupKind,
+    #[builder(default = "Vec2::ZERO")]
+    velocity: Vec2,
+    #[builder(default = "12.0")]
+    radius: f32,
+    /// Time in seconds before pickup disappears
+    #[builder(default = "15.0")]
+    time_to_live: f32,
+    /// Pickup blinks for this time in seconds before it disappears
+    #[builder(default = "4.0")]
+    blink_time: f32,
+}
+
+pub use PickupCreateInfoBuilder as PickupBuilder;
+
+impl EntityBuilder for PickupBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let shape = shapes::RegularPolygon {
+            sides: 4,
+            feature: shapes::RegularPolygonFeature::Radius(info.radius),
+            center: Vec2::ZERO,
+        };
+
+        commands
+            .insert(Pickup(info.kind))
+            .insert(ShapeBundle {
+                path: GeometryBuilder::build_as(&shape),
+                ..default()
+            })
+            .insert(Fill {
+                color: info.kind.color(),
+                options: default(),
+            })
+            .insert(Stroke {
+                color: Color::WHITE,
+                options: default(),
+            })
+            .insert(RigidBody::Dynamic)
+            .insert(Collider::ball(info.radius))
+            .insert(ColliderMassProperties::Density(1.0))
+            .insert(Sensor)
+            .insert(ActiveEvents::COLLISION_EVENTS)
+            .insert(Velocity {
+                linvel: info.velocity,
+                angvel: 1.0,
+            })
+            .insert(Damping {
+                linear_damping: 0.1,
+                angular_damping: 0.0,
+            })
+            .insert(PositionBundle::new(info.position, Layer::Main))
+            .insert(TimeToLiveBundle::new(info.time_to_live))
+            .insert(BlinkBeforeDespawn(info.blink_time))
+            .insert(DespawnOnExitGame)
+    }
+}
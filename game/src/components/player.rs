@@ -1,5 +1,7 @@
 use bevy::{ecs::system::EntityCommands, prelude::*};
 
+use crate::components::faction::Faction;
+use crate::components::pickup::PowerUps;
 use crate::entity::EntityBuilder;
 
 /// Player components mark
@@ -22,6 +24,10 @@ impl<B: EntityBuilder> EntityBuilder for PlayerDecorator<B> {
         &self,
         commands: &'c mut EntityCommands<'w, 's, 'a>,
     ) -> &'c mut EntityCommands<'w, 's, 'a> {
-        self.builder.build(commands).insert(Player)
+        self.builder
+            .build(commands)
+            .insert(Player)
+            .insert(Faction::Player)
+            .insert(PowerUps::default())
     }
 }
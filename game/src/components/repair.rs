@@ -0,0 +1,73 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+
+use crate::components::common::{DespawnOnExitGame, Layer, PositionBundle, TimeToLiveBundle};
+use crate::entity::EntityBuilder;
+
+/// Repairs ships inside the zone
+#[derive(Component)]
+pub struct RepairZone {
+    radius: f32,
+    /// Health per second
+    rate: f32,
+}
+
+impl RepairZone {
+    pub fn new(radius: f32, rate: f32) -> Self {
+        Self { radius, rate }
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+}
+
+#[derive(Builder)]
+pub struct RepairZoneCreateInfo {
+    position: Vec2,
+    #[builder(default = "300.0")]
+    radius: f32,
+    #[builder(default = "500.0")]
+    rate: f32,
+    /// Time in seconds before zone disappears
+    #[builder(default = "30.0")]
+    time_to_live: f32,
+}
+
+pub use RepairZoneCreateInfoBuilder as RepairZoneBuilder;
+
+impl EntityBuilder for RepairZoneBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let shape = shapes::Circle {
+            radius: info.radius,
+            center: Vec2::ZERO,
+        };
+
+        commands
+            .insert(RepairZone::new(info.radius, info.rate))
+            .insert(ShapeBundle {
+                path: GeometryBuilder::build_as(&shape),
+                ..default()
+            })
+            .insert(Fill {
+                color: Color::LIME_GREEN.with_a(0.1),
+                options: default(),
+            })
+            .insert(Stroke {
+                color: Color::LIME_GREEN,
+                options: default(),
+            })
+            .insert(PositionBundle::new(info.position, Layer::BackgroundHigh))
+            .insert(TimeToLiveBundle::new(info.time_to_live))
+            .insert(DespawnOnExitGame)
+    }
+}
@@ -0,0 +1,85 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::components::common::{DespawnOnExitGame, Layer, PositionBundle, TimeToLiveBundle};
+use crate::entity::EntityBuilder;
+
+/// Piece of resources that can be collected by player
+#[derive(Component)]
+pub struct ResourceFragment(u32);
+
+impl ResourceFragment {
+    pub fn new(amount: u32) -> Self {
+        Self(amount)
+    }
+
+    pub fn amount(&self) -> u32 {
+        self.0
+    }
+
+    /// Takes up to `amount` from fragment and returns taken part
+    pub fn take(&mut self, amount: u32) -> u32 {
+        let taken = amount.min(self.0);
+        self.0 -= taken;
+        taken
+    }
+}
+
+#[derive(Builder)]
+pub struct ResourceFragmentCreateInfo {
+    position: Vec2,
+    #[builder(default = "Vec2::ZERO")]
+    velocity: Vec2,
+    #[builder(default = "1")]
+    amount: u32,
+    #[builder(default = "6.0")]
+    radius: f32,
+    /// Time in seconds before fragment disappears
+    #[builder(default = "20.0")]
+    time_to_live: f32,
+}
+
+pub use ResourceFragmentCreateInfoBuilder as ResourceFragmentBuilder;
+
+impl EntityBuilder for ResourceFragmentBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let shape = shapes::Circle {
+            radius: info.radius,
+            center: Vec2::ZERO,
+        };
+
+        commands
+            .insert(ResourceFragment::new(info.amount))
+            .insert(ShapeBundle {
+                path: GeometryBuilder::build_as(&shape),
+                ..default()
+            })
+            .insert(Fill {
+                color: Color::SILVER,
+                options: default(),
+            })
+            .insert(Stroke {
+                color: Color::WHITE,
+                options: default(),
+            })
+            .insert(RigidBody::Dynamic)
+            .insert(Collider::ball(info.radius))
+            .insert(ColliderMassProperties::Density(1.0))
+            .insert(Sensor)
+            .insert(ActiveEvents::COLLISION_EVENTS)
+            .insert(Velocity::linear(info.velocity))
+            .insert(Damping {
+                linear_damping: 0.3,
+                angular_damping: 0.0,
+            })
+            .insert(PositionBundle::new(info.position, Layer::Main))
+            .insert(TimeToLiveBundle::new(info.time_to_live))
+            .insert(DespawnOnExitGame)
+    }
+}
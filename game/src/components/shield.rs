@@ -0,0 +1,152 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+
+use crate::components::common::{Layer, PositionBundle};
+use crate::entity::EntityBuilder;
+
+/// Time in seconds bubble flashes after absorbing damage
+const SHIELD_FLASH_TIME: f32 = 0.15;
+
+/// Energy shield. Absorbs damage before [Health](super::health::Health)
+#[derive(Component)]
+pub struct Shield {
+    capacity: f32,
+    charge: f32,
+    /// Time in seconds after damage before regeneration starts
+    regen_delay: f32,
+    /// Charge regenerated per second
+    regen_rate: f32,
+    since_damage: f32,
+    flash: f32,
+}
+
+impl Shield {
+    pub fn new(capacity: f32, regen_delay: f32, regen_rate: f32) -> Self {
+        Self {
+            capacity,
+            charge: capacity,
+            regen_delay,
+            regen_rate,
+            since_damage: regen_delay,
+            flash: 0.0,
+        }
+    }
+
+    pub fn capacity(&self) -> f32 {
+        self.capacity
+    }
+
+    pub fn charge(&self) -> f32 {
+        self.charge
+    }
+
+    /// Charge in [0, 1]
+    pub fn fraction(&self) -> f32 {
+        if self.capacity <= 0.0 {
+            return 0.0;
+        }
+
+        self.charge / self.capacity
+    }
+
+    /// Flash strength in [0, 1]
+    pub fn flash(&self) -> f32 {
+        self.flash / SHIELD_FLASH_TIME
+    }
+
+    /// Absorbs `damage` and returns part of it left for hull. Any damage delays regeneration
+    pub fn absorb(&mut self, damage: f32) -> f32 {
+        self.since_damage = 0.0;
+
+        let absorbed = damage.min(self.charge);
+        if 0.0 < absorbed {
+            self.charge -= absorbed;
+            self.flash = SHIELD_FLASH_TIME;
+        }
+
+        damage - absorbed
+    }
+
+    pub fn update(&mut self, time: f32) {
+        self.flash = (self.flash - time).max(0.0);
+        self.since_damage += time;
+
+        if self.regen_delay <= self.since_damage {
+            self.charge = (self.charge + self.regen_rate * time).min(self.capacity);
+        }
+    }
+
+    pub fn recharge(&mut self) {
+        self.charge = self.capacity;
+        self.since_damage = self.regen_delay;
+        self.flash = 0.0;
+    }
+}
+
+/// Visible shield bubble, child of entity with [Shield]
+#[derive(Component)]
+pub struct ShieldBubble {
+    /// Bubble alpha on full charge
+    alpha: f32,
+}
+
+impl ShieldBubble {
+    /// Alpha of bubble with shield `charge` and `flash` in [0, 1]
+    pub fn alpha(&self, charge: f32, flash: f32) -> f32 {
+        (self.alpha * charge + flash * 0.5).min(1.0)
+    }
+}
+
+#[derive(Builder, Clone)]
+pub struct ShieldCreateInfo {
+    #[builder(default = "2000.0")]
+    capacity: f32,
+    #[builder(default = "3.0")]
+    regen_delay: f32,
+    #[builder(default = "400.0")]
+    regen_rate: f32,
+    /// Bubble radius
+    #[builder(default = "40.0")]
+    radius: f32,
+    #[builder(default = "Color::CYAN")]
+    color: Color,
+}
+
+pub use ShieldCreateInfoBuilder as ShieldBuilder;
+
+impl EntityBuilder for ShieldBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let shape = shapes::Circle {
+            radius: info.radius,
+            center: Vec2::ZERO,
+        };
+
+        let bubble = ShieldBubble {
+            alpha: info.color.a() * 0.3,
+        };
+
+        commands
+            .insert(Shield::new(
+                info.capacity,
+                info.regen_delay,
+                info.regen_rate,
+            ))
+            .with_children(|cb| {
+                cb.spawn(ShapeBundle {
+                    path: GeometryBuilder::build_as(&shape),
+                    ..default()
+                })
+                .insert(Fill {
+                    color: info.color.with_a(bubble.alpha),
+                    options: default(),
+                })
+                .insert(PositionBundle::new(Vec2::ZERO, Layer::Effects))
+                .insert(bubble);
+            })
+    }
+}
@@ -5,11 +5,19 @@ use physic_objects::prelude::*;
 
 use crate::components::common::{Layer, PositionBundle};
 use crate::components::engine::{MainEngineBuilder, RotationEngineBuilder, SwayEngineBuilder};
-use crate::components::health::{CollisionDamageBundle, HealthBundle};
+use crate::components::faction::Faction;
+use crate::components::health::{CollisionDamageBundle, HealthBundle, HealthRegeneration};
 use crate::components::particle::fire::FireGeneratorBundle;
 use crate::components::particle::propulsion::PropulsionParticleGeneratorBundle;
+use crate::components::shield::ShieldBuilder;
+use crate::components::status::{InflictStatus, StatusEffect, StatusKind};
+use crate::components::weapon::loadout::{FireMode, HardpointBundle, WeaponGroup, WeaponLoadout};
 use crate::components::weapon::machinegun::MachineGunBuilder;
-use crate::entity::{BuilderConcatenator, EntityBuildDirector, EntityBuilder};
+use crate::components::weapon::missilelauncher::MissileLauncherBuilder;
+use crate::entity::{
+    BuilderConcatenator, ComponentInjectorBuilder, EntityBuildDirector, EntityBuilder,
+};
+use crate::explosion::Explosive;
 use crate::math::RotateAroundZ;
 
 use super::control::effects::*;
@@ -25,14 +33,13 @@ const SHIP_ANGLES: [f32; 3] = [0.0, 135.0, -135.0];
 pub struct ShipBuilder;
 
 impl ShipBuilder {
-    pub fn new(position: Vec2) -> impl EntityBuilder {
+    pub fn build_with(position: Vec2, shield: ShieldBuilder) -> impl EntityBuilder {
         let mut builder = ShipBaseBuilder::default();
         builder.position(position);
 
         let builder = BuilderConcatenator::new(builder, ShipEnginesBuilder);
         let builder = BuilderConcatenator::new(builder, ShipWeaponsBuilder);
-
-        builder
+        BuilderConcatenator::new(builder, shield)
     }
 }
 
@@ -67,13 +74,24 @@ impl EntityBuilder for ShipBaseBuilder {
 
         let commands = commands
             .insert(Ship)
+            .insert(Faction::Hostile)
             .insert(physic_object)
             .insert(Fill {
                 color: Color::BLUE,
                 options: Default::default(),
             })
             .insert(HealthBundle::new(10000.0))
-            .insert(CollisionDamageBundle::new())
+            // Hull slowly repairs itself to 75%
+            .insert(HealthRegeneration::new(100.0, 5.0, 0.75))
+            // Heavy hull ignores small debris
+            .insert(CollisionDamageBundle::new(50.0, 100.0))
+            .insert(Explosive::new(200.0, 3_000_000.0, 1000.0))
+            // Reactor blast disables nearby ships
+            .insert(InflictStatus::new(StatusEffect::new(
+                StatusKind::Emp,
+                3.0,
+                1.0,
+            )))
             .insert(PositionBundle::new(create_info.position, Layer::Main))
             .with_children(|cb| {
                 // Fire effects when damaged
@@ -194,12 +212,39 @@ impl EntityBuilder for ShipWeaponsBuilder {
         &self,
         commands: &'c mut EntityCommands<'w, 's, 'a>,
     ) -> &'c mut EntityCommands<'w, 's, 'a> {
-        commands.with_children(|cb| {
-            cb.build_entity(
-                MachineGunBuilder::default()
-                    .firerate(4.0)
-                    .position(Vec2::X * 33.0),
-            );
+        let loadout = WeaponLoadout::new(FireMode::Alternating)
+            .with_slots(WeaponGroup::Primary, 2)
+            .with_slots(WeaponGroup::Secondary, 1);
+
+        commands.insert(loadout).with_children(|cb| {
+            // Primary 1: nose machine gun
+            let mut machine_gun = MachineGunBuilder::default();
+            machine_gun.firerate(4.0).position(Vec2::X * 33.0);
+            cb.build_entity(&ComponentInjectorBuilder::new(
+                machine_gun,
+                HardpointBundle::new(WeaponGroup::Primary, 0, "Machine gun"),
+            ));
+
+            // Primary 2: twin guns on wings
+            for side in [1.0, -1.0] {
+                let mut twin_gun = MachineGunBuilder::default();
+                twin_gun
+                    .firerate(3.0)
+                    .position(Vec2::new(10.0, 14.0 * side))
+                    .status(StatusEffect::new(StatusKind::Corroded, 5.0, 20.0));
+                cb.build_entity(&ComponentInjectorBuilder::new(
+                    twin_gun,
+                    HardpointBundle::new(WeaponGroup::Primary, 1, "Twin guns"),
+                ));
+            }
+
+            // Secondary 1: missiles
+            let mut launcher = MissileLauncherBuilder::default();
+            launcher.firerate(1.0).position(Vec2::X * 42.0);
+            cb.build_entity(&ComponentInjectorBuilder::new(
+                launcher,
+                HardpointBundle::new(WeaponGroup::Secondary, 0, "Missiles"),
+            ));
         })
     }
 }
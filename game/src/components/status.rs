@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+/// Period in seconds between effect ticks
+pub const STATUS_TICK_PERIOD: f32 = 0.5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Damage over time
+    Burning,
+    /// Disables engines and weapons
+    Emp,
+    /// Extra linear damping
+    Slowed,
+    /// Reduced armor
+    Corroded,
+}
+
+impl StatusKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusKind::Burning => "Burning",
+            StatusKind::Emp => "EMP",
+            StatusKind::Slowed => "Slowed",
+            StatusKind::Corroded => "Corroded",
+        }
+    }
+
+    /// Max stacks of effect. Effects with one stack only refresh duration
+    pub fn max_stacks(&self) -> u32 {
+        match self {
+            StatusKind::Burning => 5,
+            StatusKind::Corroded => 3,
+            StatusKind::Emp | StatusKind::Slowed => 1,
+        }
+    }
+}
+
+/// Timed effect. Strength grows with stacks
+#[derive(Clone, Copy, Debug)]
+pub struct StatusEffect {
+    kind: StatusKind,
+    /// Time left in seconds
+    duration: f32,
+    /// Strength of one stack: damage per second, damping or armor reduction
+    magnitude: f32,
+    stacks: u32,
+    since_tick: f32,
+    /// Entity credited for damage of effect
+    source: Option<Entity>,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusKind, duration: f32, magnitude: f32) -> Self {
+        Self {
+            kind,
+            duration,
+            magnitude,
+            stacks: 1,
+            since_tick: 0.0,
+            source: None,
+        }
+    }
+
+    pub fn with_source(mut self, source: Option<Entity>) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn kind(&self) -> StatusKind {
+        self.kind
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    pub fn source(&self) -> Option<Entity> {
+        self.source
+    }
+
+    pub fn stacks(&self) -> u32 {
+        self.stacks
+    }
+
+    pub fn strength(&self) -> f32 {
+        self.magnitude * self.stacks as f32
+    }
+
+    pub fn expired(&self) -> bool {
+        self.duration <= 0.0
+    }
+
+    /// Adds stack up to limit of kind and refreshes duration
+    fn stack(&mut self, other: &StatusEffect) {
+        self.stacks = (self.stacks + other.stacks).min(self.kind.max_stacks());
+        self.duration = self.duration.max(other.duration);
+        self.magnitude = self.magnitude.max(other.magnitude);
+        self.source = other.source.or(self.source);
+    }
+
+    /// Returns count of ticks passed
+    fn update(&mut self, time: f32) -> u32 {
+        self.duration -= time;
+        self.since_tick += time;
+
+        let ticks = (self.since_tick / STATUS_TICK_PERIOD) as u32;
+        self.since_tick -= ticks as f32 * STATUS_TICK_PERIOD;
+
+        ticks
+    }
+}
+
+/// Active effects of entity
+#[derive(Component, Default)]
+pub struct StatusEffects(Vec<StatusEffect>);
+
+impl StatusEffects {
+    pub fn apply(&mut self, effect: StatusEffect) {
+        match self.0.iter_mut().find(|e| e.kind == effect.kind) {
+            Some(existing) => existing.stack(&effect),
+            None => self.0.push(effect),
+        }
+    }
+
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.0.iter().find(|e| e.kind == kind)
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    /// Strength of effect or zero if it's not active
+    pub fn strength(&self, kind: StatusKind) -> f32 {
+        self.get(kind).map_or(0.0, |e| e.strength())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Updates durations, removes expired effects and returns ticks passed for every effect
+    pub fn update(&mut self, time: f32) -> Vec<(StatusEffect, u32)> {
+        let ticks = self
+            .0
+            .iter_mut()
+            .map(|effect| {
+                let ticks = effect.update(time);
+                (*effect, ticks)
+            })
+            .collect();
+
+        self.0.retain(|effect| !effect.expired());
+
+        ticks
+    }
+}
+
+/// Weapons and hazards with this component apply effect to what they hit
+#[derive(Component, Clone, Copy)]
+pub struct InflictStatus(StatusEffect);
+
+impl InflictStatus {
+    pub fn new(effect: StatusEffect) -> Self {
+        Self(effect)
+    }
+
+    pub fn effect(&self) -> StatusEffect {
+        self.0
+    }
+}
+
+/// Linear damping of entity without [StatusKind::Slowed]
+#[derive(Component, Clone, Copy)]
+pub struct BaseDamping(pub f32);
+
+/// Entity can't use engines and weapons
+#[derive(Component)]
+pub struct Disabled;
+
+/// Fire particles of [StatusKind::Burning]
+#[derive(Component)]
+pub struct BurningEffect;
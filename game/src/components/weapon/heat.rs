@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+/// Weapon heat. Each shot heats the weapon up, when heat reaches maximum weapon is locked
+/// until it cools down to recover level
+#[derive(Component)]
+pub struct WeaponHeat {
+    /// Current heat in [0, 1]
+    heat: f32,
+    /// Heat added by one shot
+    per_shot: f32,
+    /// Heat removed per second
+    cooling: f32,
+    /// Heat level on which overheated weapon can fire again
+    recover: f32,
+    overheated: bool,
+}
+
+impl WeaponHeat {
+    pub fn new(per_shot: f32, cooling: f32, recover: f32) -> Self {
+        Self {
+            heat: 0.0,
+            per_shot,
+            cooling,
+            recover,
+            overheated: false,
+        }
+    }
+
+    pub fn heat(&self) -> f32 {
+        self.heat
+    }
+
+    pub fn can_fire(&self) -> bool {
+        !self.overheated
+    }
+
+    /// Heats weapon up by one shot
+    pub fn shot(&mut self) {
+        self.heat += self.per_shot;
+
+        if 1.0 <= self.heat {
+            self.heat = 1.0;
+            self.overheated = true;
+        }
+    }
+
+    /// Cools weapon down
+    pub fn update(&mut self, time: f32) {
+        self.heat = (self.heat - self.cooling * time).max(0.0);
+
+        if self.overheated && self.heat <= self.recover {
+            self.overheated = false;
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+/// Group of weapons on ship. Each group has its own trigger
+#[derive(Component, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum WeaponGroup {
+    Primary,
+    Secondary,
+}
+
+/// Index of weapon in group.
+/// Weapons with the same group and slot are hardpoints of one weapon and fire together
+#[derive(Component, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct WeaponSlot(usize);
+
+impl WeaponSlot {
+    pub fn new(slot: usize) -> Self {
+        Self(slot)
+    }
+
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// Displayable name of weapon
+#[derive(Component, Clone)]
+pub struct WeaponName(String);
+
+impl WeaponName {
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Places weapon in ship loadout
+#[derive(Bundle, Clone)]
+pub struct HardpointBundle {
+    group: WeaponGroup,
+    slot: WeaponSlot,
+    name: WeaponName,
+}
+
+impl HardpointBundle {
+    pub fn new(group: WeaponGroup, slot: usize, name: &str) -> Self {
+        Self {
+            group,
+            slot: WeaponSlot::new(slot),
+            name: WeaponName::new(name),
+        }
+    }
+}
+
+/// How hardpoints of one slot fire
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FireMode {
+    /// All hardpoints fire at once
+    Linked,
+    /// Hardpoints fire one after another
+    Alternating,
+}
+
+impl FireMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FireMode::Linked => "Linked",
+            FireMode::Alternating => "Alternating",
+        }
+    }
+}
+
+/// Weapons selection and triggers of ship
+#[derive(Component)]
+pub struct WeaponLoadout {
+    /// Count of slots in each group
+    slots: HashMap<WeaponGroup, usize>,
+    /// Selected slot in each group
+    active: HashMap<WeaponGroup, usize>,
+    /// Pulled triggers
+    triggers: HashMap<WeaponGroup, bool>,
+    fire_mode: FireMode,
+}
+
+impl WeaponLoadout {
+    pub fn new(fire_mode: FireMode) -> Self {
+        Self {
+            slots: HashMap::new(),
+            active: HashMap::new(),
+            triggers: HashMap::new(),
+            fire_mode,
+        }
+    }
+
+    /// Sets count of slots in group
+    pub fn with_slots(mut self, group: WeaponGroup, count: usize) -> Self {
+        self.slots.insert(group, count);
+        self
+    }
+
+    pub fn fire_mode(&self) -> FireMode {
+        self.fire_mode
+    }
+
+    pub fn toggle_fire_mode(&mut self) {
+        self.fire_mode = match self.fire_mode {
+            FireMode::Linked => FireMode::Alternating,
+            FireMode::Alternating => FireMode::Linked,
+        };
+    }
+
+    /// Returns selected slot in group
+    pub fn active(&self, group: WeaponGroup) -> usize {
+        self.active.get(&group).cloned().unwrap_or_default()
+    }
+
+    /// Selects slot in group. Does nothing if group doesn't have such slot
+    pub fn select(&mut self, group: WeaponGroup, slot: usize) {
+        if slot < self.slots_count(group) {
+            self.active.insert(group, slot);
+        }
+    }
+
+    /// Selects next slot in group
+    pub fn next(&mut self, group: WeaponGroup) {
+        let count = self.slots_count(group);
+        if 0 < count {
+            let slot = (self.active(group) + 1) % count;
+            self.active.insert(group, slot);
+        }
+    }
+
+    /// Selects previous slot in group
+    pub fn previous(&mut self, group: WeaponGroup) {
+        let count = self.slots_count(group);
+        if 0 < count {
+            let slot = (self.active(group) + count - 1) % count;
+            self.active.insert(group, slot);
+        }
+    }
+
+    pub fn is_active(&self, group: WeaponGroup, slot: WeaponSlot) -> bool {
+        self.active(group) == slot.index()
+    }
+
+    pub fn pull(&mut self, group: WeaponGroup) {
+        self.triggers.insert(group, true);
+    }
+
+    pub fn release(&mut self, group: WeaponGroup) {
+        self.triggers.insert(group, false);
+    }
+
+    pub fn triggered(&self, group: WeaponGroup) -> bool {
+        self.triggers.get(&group).cloned().unwrap_or_default()
+    }
+
+    fn slots_count(&self, group: WeaponGroup) -> usize {
+        self.slots.get(&group).cloned().unwrap_or_default()
+    }
+}
@@ -1,11 +1,12 @@
 use bevy::{ecs::system::EntityCommands, prelude::*};
 
 use crate::components::common::{Layer, PositionBundle};
+use crate::components::status::StatusEffect;
 use crate::entity::EntityBuilder;
 
 use super::projectile::bullet::BulletBuilder;
 use super::projectile::ProjectileCreator;
-use super::Weapon;
+use super::{Weapon, WeaponHeat};
 
 #[derive(Component)]
 pub struct MachineGun;
@@ -17,6 +18,15 @@ pub struct MachineGunCreateInfo {
     firerate: f32,
     #[builder(default = "Vec2::ZERO")]
     position: Vec2,
+    /// Heat added by one shot
+    #[builder(default = "0.06")]
+    heat_per_shot: f32,
+    /// Heat removed per second
+    #[builder(default = "0.12")]
+    cooling: f32,
+    /// Effect applied by bullets
+    #[builder(default, setter(strip_option))]
+    status: Option<StatusEffect>,
 }
 
 impl EntityBuilder for MachineGunBuilder {
@@ -28,10 +38,14 @@ impl EntityBuilder for MachineGunBuilder {
 
         let mut bullet_gen = BulletBuilder::default();
         bullet_gen.radius(2.0).density(50.0);
+        if let Some(status) = info.status {
+            bullet_gen.status(status);
+        }
 
         commands
             .insert(MachineGun)
             .insert(Weapon::new(info.firerate, 500.0, 0.05))
+            .insert(WeaponHeat::new(info.heat_per_shot, info.cooling, 0.3))
             .insert(ProjectileCreator::new(bullet_gen))
             .insert(PositionBundle::new(info.position, Layer::Main))
     }
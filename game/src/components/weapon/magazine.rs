@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+/// Finite magazine of weapon. Weapon can't fire while magazine is empty or reloading
+#[derive(Component)]
+pub struct Magazine {
+    capacity: u32,
+    rounds: u32,
+    /// Time in seconds to reload magazine
+    reload_time: f32,
+    /// Time left to finish reload. `None` if weapon is not reloading
+    reload_left: Option<f32>,
+}
+
+impl Magazine {
+    /// Creates full magazine
+    pub fn new(capacity: u32, reload_time: f32) -> Self {
+        Self {
+            capacity,
+            rounds: capacity,
+            reload_time,
+            reload_left: None,
+        }
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+
+    /// Count of rounds needed to fill magazine
+    pub fn missing(&self) -> u32 {
+        self.capacity - self.rounds
+    }
+
+    pub fn empty(&self) -> bool {
+        self.rounds == 0
+    }
+
+    pub fn reloading(&self) -> bool {
+        self.reload_left.is_some()
+    }
+
+    /// Returns reload progress in [0, 1]. Returns 0.0 if weapon is not reloading
+    pub fn reload_progress(&self) -> f32 {
+        match self.reload_left {
+            Some(left) => 1.0 - left / self.reload_time,
+            None => 0.0,
+        }
+    }
+
+    pub fn can_fire(&self) -> bool {
+        !self.reloading() && !self.empty()
+    }
+
+    /// Takes one round from magazine
+    pub fn consume(&mut self) {
+        self.rounds = self.rounds.saturating_sub(1);
+    }
+
+    /// Starts reload if magazine is not full
+    pub fn start_reload(&mut self) {
+        if !self.reloading() && 0 < self.missing() {
+            self.reload_left = Some(self.reload_time);
+        }
+    }
+
+    /// Updates reload timer. Returns true when reload is finished
+    pub fn update(&mut self, time: f32) -> bool {
+        let Some(left) = self.reload_left.as_mut() else {
+            return false;
+        };
+
+        *left -= time;
+
+        if *left <= 0.0 {
+            self.reload_left = None;
+            return true;
+        }
+
+        false
+    }
+
+    /// Puts rounds into magazine
+    pub fn refill(&mut self, rounds: u32) {
+        self.rounds = (self.rounds + rounds).min(self.capacity);
+    }
+}
+
+/// Rounds stored outside of magazine. Weapon with [Magazine] but without reserve has infinite ammo
+#[derive(Component)]
+pub struct AmmoReserve(u32);
+
+impl AmmoReserve {
+    pub fn new(rounds: u32) -> Self {
+        Self(rounds)
+    }
+
+    pub fn rounds(&self) -> u32 {
+        self.0
+    }
+
+    /// Takes up to `count` rounds from reserve. Returns count of taken rounds
+    pub fn take(&mut self, count: u32) -> u32 {
+        let taken = count.min(self.0);
+        self.0 -= taken;
+        taken
+    }
+}
+
+/// Tells weapon to reload magazine on next update
+#[derive(Component)]
+pub struct Reload;
@@ -0,0 +1,45 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+use crate::components::common::{Layer, PositionBundle};
+use crate::entity::EntityBuilder;
+
+use super::projectile::missile::MissileBuilder;
+use super::projectile::ProjectileCreator;
+use super::{AmmoReserve, Magazine, Weapon};
+
+#[derive(Component)]
+pub struct MissileLauncher;
+
+#[derive(Builder)]
+#[builder(name = "MissileLauncherBuilder")]
+pub struct MissileLauncherCreateInfo {
+    #[builder(default = "0.5")]
+    firerate: f32,
+    #[builder(default = "Vec2::ZERO")]
+    position: Vec2,
+    #[builder(default = "4")]
+    magazine: u32,
+    /// Time in seconds to reload magazine
+    #[builder(default = "3.0")]
+    reload_time: f32,
+    /// Missiles in reserve
+    #[builder(default = "16")]
+    reserve: u32,
+}
+
+impl EntityBuilder for MissileLauncherBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        commands
+            .insert(MissileLauncher)
+            .insert(Weapon::new(info.firerate, 100.0, 0.02))
+            .insert(Magazine::new(info.magazine, info.reload_time))
+            .insert(AmmoReserve::new(info.reserve))
+            .insert(ProjectileCreator::new(MissileBuilder::default()))
+            .insert(PositionBundle::new(info.position, Layer::Main))
+    }
+}
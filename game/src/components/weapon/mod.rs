@@ -3,15 +3,32 @@ pub use bevy::prelude::*;
 /// Projectiles for weapons
 pub mod projectile;
 
+/// Finite ammunition and reload
+pub mod magazine;
+pub use magazine::{AmmoReserve, Magazine, Reload};
+
+/// Heat buildup and overheat lockout
+pub mod heat;
+pub use heat::WeaponHeat;
+
+/// Weapon groups, slots and selection
+pub mod loadout;
+pub use loadout::{FireMode, WeaponGroup, WeaponLoadout, WeaponName, WeaponSlot};
+
 /// Basic bullet launcher
 pub mod machinegun;
 pub use machinegun::{MachineGun, MachineGunBuilder};
 
+/// Guided missiles launcher
+pub mod missilelauncher;
+
 /// Basic weapon component with firerate checking
 #[derive(Component)]
 pub struct Weapon {
     /// Firerate in shots per second
     firerate: f32,
+    /// Temporary firerate boost, e.g. by power-up
+    firerate_multiplier: f32,
     /// Setted automaticaly, represent minimal time to make next shoot
     time_to_shot: f32,
     /// Projectile velocity on fire
@@ -25,6 +42,7 @@ impl Weapon {
         Self {
             time_to_shot: 0.0,
             firerate,
+            firerate_multiplier: 1.0,
             velocity,
             accuracy,
         }
@@ -39,12 +57,26 @@ impl Weapon {
     /// Returns true if gun should fire
     pub fn fire(&mut self) -> bool {
         if self.time_to_shot <= 0.0 {
-            self.time_to_shot = 1.0 / self.firerate;
+            self.time_to_shot = 1.0 / self.firerate();
             return true;
         }
         false
     }
 
+    /// Postpones next shot for at least `time` seconds
+    pub fn delay(&mut self, time: f32) {
+        self.time_to_shot = self.time_to_shot.max(time);
+    }
+
+    /// Firerate with boost applied
+    pub fn firerate(&self) -> f32 {
+        self.firerate * self.firerate_multiplier
+    }
+
+    pub fn set_firerate_multiplier(&mut self, multiplier: f32) {
+        self.firerate_multiplier = multiplier;
+    }
+
     pub fn accuracy(&self) -> f32 {
         self.accuracy
     }
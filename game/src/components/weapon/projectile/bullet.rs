@@ -1,13 +1,13 @@
 use bevy::{ecs::system::EntityCommands, prelude::*};
 use bevy_prototype_lyon::prelude::*;
-use bevy_rapier2d::prelude::{Ccd, ColliderMassProperties, RigidBody};
+use bevy_rapier2d::prelude::{ActiveEvents, Ccd, ColliderMassProperties, RigidBody, Sensor};
 use physic_objects::prelude::*;
 
-use super::{Projectile, ProjectileEntityBuilder};
+use super::{Projectile, ProjectileEntityBuilder, ProjectileHitBundle, Ricochet};
 use crate::{
     components::{
         common::{DespawnOnExitGame, DespawnOnOutOfRange, TimeToLiveBundle},
-        health::{CollisionDamageBundle, Health},
+        status::{InflictStatus, StatusEffect},
     },
     entity::EntityBuilder,
 };
@@ -18,6 +18,17 @@ pub struct BulletCreateInfo {
     radius: f32,
     #[builder(default = "10.0")]
     density: f32,
+    #[builder(default = "40.0")]
+    damage: f32,
+    /// Count of targets bullet passes through
+    #[builder(default = "0")]
+    piercing: u32,
+    /// Max angle to surface in radians to bounce off it
+    #[builder(default = "15.0_f32.to_radians()")]
+    ricochet_angle: f32,
+    /// Effect applied on hit
+    #[builder(default, setter(strip_option))]
+    status: Option<StatusEffect>,
 }
 
 pub use BulletCreateInfoBuilder as BulletBuilder;
@@ -37,6 +48,10 @@ impl EntityBuilder for BulletBuilder {
             })
             .build();
 
+        if let Some(status) = info.status {
+            commands.insert(InflictStatus::new(status));
+        }
+
         commands
             .insert(Projectile)
             .insert(physic_object)
@@ -44,12 +59,14 @@ impl EntityBuilder for BulletBuilder {
                 color: Color::GOLD,
                 options: Default::default(),
             })
+            .insert(Sensor)
+            .insert(ActiveEvents::COLLISION_EVENTS)
             .insert(Ccd::enabled())
             .insert(DespawnOnOutOfRange)
             .insert(DespawnOnExitGame)
             .insert(TimeToLiveBundle::new(60.0))
-            .insert(Health::new(info.radius * info.density * 1.5))
-            .insert(CollisionDamageBundle::new())
+            .insert(ProjectileHitBundle::new(info.damage, info.piercing))
+            .insert(Ricochet::new(info.ricochet_angle, 0.7))
     }
 }
 
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+/// Damage dealt by projectile on hit
+#[derive(Component, Clone, Copy)]
+pub struct ProjectileDamage(f32);
+
+impl ProjectileDamage {
+    pub fn new(damage: f32) -> Self {
+        Self(damage)
+    }
+
+    pub fn damage(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Projectile passes through this count of targets before it's spent
+#[derive(Component, Clone, Copy)]
+pub struct Piercing(u32);
+
+impl Piercing {
+    pub fn new(targets: u32) -> Self {
+        Self(targets)
+    }
+
+    /// Returns true if projectile passes through current target
+    pub fn pierce(&mut self) -> bool {
+        if self.0 == 0 {
+            return false;
+        }
+
+        self.0 -= 1;
+        true
+    }
+}
+
+/// Projectile bounces off surfaces hit at shallow angles
+#[derive(Component, Clone, Copy)]
+pub struct Ricochet {
+    /// Max angle between projectile direction and surface in radians
+    max_angle: f32,
+    /// Part of speed left after bounce
+    restitution: f32,
+}
+
+impl Ricochet {
+    pub fn new(max_angle: f32, restitution: f32) -> Self {
+        Self {
+            max_angle,
+            restitution,
+        }
+    }
+
+    /// Returns velocity after bounce off surface with `normal` or None if angle is too steep
+    pub fn bounce(&self, velocity: Vec2, normal: Vec2) -> Option<Vec2> {
+        let direction = velocity.normalize_or_zero();
+
+        // Sine of angle between direction and surface
+        let incidence = direction.dot(normal).abs();
+        if self.max_angle.sin() < incidence {
+            return None;
+        }
+
+        let reflected = velocity - 2.0 * velocity.dot(normal) * normal;
+        Some(reflected * self.restitution)
+    }
+}
+
+/// Targets already hit by projectile. Each target is hit only once
+#[derive(Component, Default)]
+pub struct ProjectileHits(Vec<Entity>);
+
+impl ProjectileHits {
+    pub fn contains(&self, target: Entity) -> bool {
+        self.0.contains(&target)
+    }
+
+    pub fn push(&mut self, target: Entity) {
+        self.0.push(target);
+    }
+}
+
+/// Everything projectile needs to resolve hits through collision events
+#[derive(Bundle)]
+pub struct ProjectileHitBundle {
+    damage: ProjectileDamage,
+    piercing: Piercing,
+    hits: ProjectileHits,
+}
+
+impl ProjectileHitBundle {
+    pub fn new(damage: f32, piercing: u32) -> Self {
+        Self {
+            damage: ProjectileDamage::new(damage),
+            piercing: Piercing::new(piercing),
+            hits: ProjectileHits::default(),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy_prototype_lyon::prelude::*;
+use bevy_rapier2d::prelude::{ActiveEvents, ColliderMassProperties, RigidBody};
+use physic_objects::prelude::*;
+
+use super::{Projectile, ProjectileEntityBuilder};
+use crate::components::common::{DespawnOnExitGame, DespawnOnOutOfRange};
+use crate::components::engine::{MainEngineBuilder, RotationEngineBuilder};
+use crate::components::particle::propulsion::PropulsionParticleGeneratorBundle;
+use crate::components::ship::control::effects::ForwardEngineEffect;
+use crate::components::ship::control::rotation::RotationControlBuilder;
+use crate::components::ship::control::ShipEngineControllerBundle;
+use crate::components::status::{InflictStatus, StatusEffect, StatusKind};
+use crate::entity::{EntityBuildDirector, EntityBuilder};
+use crate::explosion::Explosive;
+use crate::math::RotateAroundZ;
+
+/// Radius vector will be rotated by this angles to create points
+const MISSILE_ANGLES: [f32; 3] = [0.0, 150.0, -150.0];
+
+/// Missile mark
+#[derive(Component)]
+pub struct Missile;
+
+/// Fuel left in missile tank. Main engine burns one unit per second on full throttle
+#[derive(Component)]
+pub struct Fuel(f32);
+
+impl Fuel {
+    pub fn new(fuel: f32) -> Self {
+        Self(fuel)
+    }
+
+    pub fn burn(&mut self, amount: f32) {
+        self.0 -= amount;
+    }
+
+    pub fn empty(&self) -> bool {
+        self.0 <= 0.0
+    }
+}
+
+/// Homing head of missile. Describes area in front of the missile where targets can be locked
+#[derive(Component)]
+pub struct Seeker {
+    /// Max distance to target
+    range: f32,
+    /// Max angle between missile direction and target in radians
+    cone: f32,
+}
+
+impl Seeker {
+    pub fn new(range: f32, cone: f32) -> Self {
+        Self { range, cone }
+    }
+
+    pub fn range(&self) -> f32 {
+        self.range
+    }
+
+    /// Returns true if `point` can be seen by missile at `position` heading to `direction`
+    pub fn sees(&self, position: Vec2, direction: Vec2, point: Vec2) -> bool {
+        let to_point = point - position;
+
+        to_point.length() <= self.range && direction.angle_between(to_point).abs() <= self.cone
+    }
+}
+
+/// Target locked by missile's [Seeker]
+#[derive(Component)]
+pub struct MissileTarget(Entity);
+
+impl MissileTarget {
+    pub fn new(target: Entity) -> Self {
+        Self(target)
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+#[derive(Builder)]
+pub struct MissileCreateInfo {
+    /// Radius of missile body
+    #[builder(default = "8.0")]
+    size: f32,
+    #[builder(default = "5.0")]
+    density: f32,
+    /// Main engine force
+    #[builder(default = "150_000.0")]
+    force: f32,
+    /// Rotation engine torque
+    #[builder(default = "300_000.0")]
+    torque: f32,
+    /// Fuel in seconds of full throttle burn
+    #[builder(default = "4.0")]
+    fuel: f32,
+    #[builder(default = "1500.0")]
+    seeker_range: f32,
+    #[builder(default = "45.0_f32.to_radians()")]
+    seeker_cone: f32,
+    #[builder(default = "120.0")]
+    explosion_radius: f32,
+    #[builder(default = "1_000_000.0")]
+    explosion_impulse: f32,
+    #[builder(default = "500.0")]
+    explosion_damage: f32,
+}
+
+pub use MissileCreateInfoBuilder as MissileBuilder;
+
+impl EntityBuilder for MissileBuilder {
+    fn build<'w, 's, 'a, 'c>(
+        &self,
+        commands: &'c mut EntityCommands<'w, 's, 'a>,
+    ) -> &'c mut EntityCommands<'w, 's, 'a> {
+        let info = self.build().unwrap();
+
+        let mut points = [Vec2::ZERO; 3];
+        for i in 0..MISSILE_ANGLES.len() {
+            let angle = MISSILE_ANGLES[i];
+            let vector = Vec2::X * info.size;
+            points[i] = vector.rotate_z(angle.to_radians());
+        }
+
+        let physic_object = TriangleBuilder::default()
+            .params(PhysicObjectParams {
+                body: RigidBody::Dynamic,
+                mass_properties: ColliderMassProperties::Density(info.density),
+            })
+            .points(points)
+            .build();
+
+        let commands = commands
+            .insert(Projectile)
+            .insert(Missile)
+            .insert(physic_object)
+            .insert(Fill {
+                color: Color::SILVER,
+                options: Default::default(),
+            })
+            .insert(ActiveEvents::COLLISION_EVENTS)
+            .insert(Fuel::new(info.fuel))
+            .insert(Seeker::new(info.seeker_range, info.seeker_cone))
+            .insert(Explosive::new(
+                info.explosion_radius,
+                info.explosion_impulse,
+                info.explosion_damage,
+            ))
+            // Warhead sets targets on fire
+            .insert(InflictStatus::new(StatusEffect::new(
+                StatusKind::Burning,
+                4.0,
+                50.0,
+            )))
+            .insert(DespawnOnOutOfRange)
+            .insert(DespawnOnExitGame)
+            .with_children(|cb| {
+                cb.spawn(ShipEngineControllerBundle::new())
+                    .with_children(|cb| {
+                        cb.build_entity(RotationEngineBuilder::default().torque(info.torque));
+                    })
+                    .with_children(|cb| {
+                        cb.build_entity(MainEngineBuilder::default().force(info.force));
+                    })
+                    // Engine trail
+                    .with_children(|cb| {
+                        cb.spawn(PropulsionParticleGeneratorBundle::new(
+                            0.1,
+                            3.0,
+                            1.0,
+                            Transform::from_translation(Vec3::NEG_X * info.size),
+                        ))
+                        .insert(ForwardEngineEffect);
+                    });
+            });
+
+        EntityBuilder::build(&RotationControlBuilder::default(), commands)
+    }
+}
+
+impl ProjectileEntityBuilder for MissileBuilder {}
@@ -2,13 +2,23 @@ use bevy::{ecs::system::EntityCommands, prelude::*};
 use bevy_rapier2d::prelude::Velocity;
 
 use crate::{
-    components::common::{Layer, PositionBundle},
+    components::{
+        common::{Layer, PositionBundle},
+        faction::{Faction, ProjectileOwner},
+    },
     entity::EntityBuilder,
 };
 
-/// Simple bullet implementation. It deals damage by collision
+/// Simple bullet implementation. It is a sensor: hits, piercing and ricochet are resolved by weapon plugin
 pub mod bullet;
 
+/// Projectile damage, piercing and ricochet
+pub mod hit;
+pub use hit::{Piercing, ProjectileDamage, ProjectileHitBundle, ProjectileHits, Ricochet};
+
+/// Guided missile with its own engines. It explodes on contact or when fuel runs out
+pub mod missile;
+
 /// Mark for all projectiles
 #[derive(Component)]
 pub struct Projectile;
@@ -28,6 +38,8 @@ pub trait ProjectileEntityBuilder: EntityBuilder + Send + Sync {}
 pub struct ProjectileCreateInfo {
     position: Vec2,
     velocity: Vec2,
+    #[builder(default = "0.0")]
+    angle: f32,
 }
 
 impl EntityBuilder for ProjectileDecorator {
@@ -38,7 +50,7 @@ impl EntityBuilder for ProjectileDecorator {
         let info = self.build().unwrap();
         commands
             .insert(Velocity::linear(info.velocity))
-            .insert(PositionBundle::new(info.position, Layer::Main))
+            .insert(PositionBundle::new(info.position, Layer::Main).with_angle(info.angle))
     }
 }
 
@@ -46,6 +58,8 @@ impl EntityBuilder for ProjectileDecorator {
 pub struct ProjectileCreator {
     builder: Box<dyn ProjectileEntityBuilder>,
     decorator: ProjectileDecorator,
+    /// Shooter and its faction stamped on each projectile
+    owner: Option<(Entity, Option<Faction>)>,
 }
 
 impl ProjectileCreator {
@@ -53,6 +67,7 @@ impl ProjectileCreator {
         Self {
             builder: Box::new(projectile_builder),
             decorator: ProjectileDecorator::default(),
+            owner: None,
         }
     }
 
@@ -63,6 +78,14 @@ impl ProjectileCreator {
     pub fn set_velocity(&mut self, velocity: Vec2) {
         self.decorator.velocity(velocity);
     }
+
+    pub fn set_angle(&mut self, angle: f32) {
+        self.decorator.angle(angle);
+    }
+
+    pub fn set_owner(&mut self, owner: Entity, faction: Option<Faction>) {
+        self.owner = Some((owner, faction));
+    }
 }
 
 impl EntityBuilder for ProjectileCreator {
@@ -73,6 +96,17 @@ impl EntityBuilder for ProjectileCreator {
         // build base projectile
         let projectile = EntityBuilder::build(self.builder.as_ref(), commands);
         // Apply transformation and velocity
-        EntityBuilder::build(&self.decorator, projectile)
+        let projectile = EntityBuilder::build(&self.decorator, projectile);
+
+        // Credit projectile to shooter
+        if let Some((owner, faction)) = self.owner {
+            projectile.insert(ProjectileOwner::new(owner));
+
+            if let Some(faction) = faction {
+                projectile.insert(faction);
+            }
+        }
+
+        projectile
     }
 }
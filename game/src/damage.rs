@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+
+use crate::components::status::StatusEffect;
+
+/// Damage type. Used for resistances and death causes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DamageKind {
+    Collision,
+    Projectile,
+    Explosion,
+    /// Damage over time of burning status
+    Burning,
+}
+
+impl DamageKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DamageKind::Collision => "Collision",
+            DamageKind::Projectile => "Projectile",
+            DamageKind::Explosion => "Explosion",
+            DamageKind::Burning => "Burning",
+        }
+    }
+}
+
+/// Where and from which direction damage was dealt
+#[derive(Clone, Copy, Debug)]
+pub struct Hit {
+    /// Point on target surface in world coordinates
+    pub point: Vec2,
+    /// Normalized direction of impact impulse
+    pub direction: Vec2,
+}
+
+impl Hit {
+    pub fn new(point: Vec2, direction: Vec2) -> Self {
+        Self {
+            point,
+            direction: direction.normalize_or_zero(),
+        }
+    }
+}
+
+/// Request to damage entity. Applied by [LivingPlugin](crate::plugins::living::LivingPlugin)
+/// with immunity and resistances of target
+#[derive(Clone, Debug)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    /// Entity credited for damage
+    pub source: Option<Entity>,
+    pub kind: DamageKind,
+    /// Impact point and direction, if damage has them
+    pub hit: Option<Hit>,
+}
+
+impl DamageEvent {
+    pub fn new(target: Entity, amount: f32, kind: DamageKind) -> Self {
+        Self {
+            target,
+            amount,
+            source: None,
+            kind,
+            hit: None,
+        }
+    }
+
+    pub fn with_source(mut self, source: Option<Entity>) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_hit(mut self, point: Vec2, direction: Vec2) -> Self {
+        self.hit = Some(Hit::new(point, direction));
+        self
+    }
+}
+
+/// Request to heal entity up to its max health
+#[derive(Clone, Debug)]
+pub struct HealEvent {
+    pub target: Entity,
+    pub amount: f32,
+}
+
+impl HealEvent {
+    pub fn new(target: Entity, amount: f32) -> Self {
+        Self { target, amount }
+    }
+}
+
+/// Request to apply status effect to entity
+#[derive(Clone, Debug)]
+pub struct StatusEvent {
+    pub target: Entity,
+    pub effect: StatusEffect,
+}
+
+impl StatusEvent {
+    pub fn new(target: Entity, effect: StatusEffect) -> Self {
+        Self { target, effect }
+    }
+}
+
+/// Sent for damage that actually reduced health: after immunity, resistances and shield.
+/// Overkill part is not included
+#[derive(Clone, Debug)]
+pub struct AppliedDamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    /// Entity credited for damage
+    pub source: Option<Entity>,
+    pub kind: DamageKind,
+}
+
+/// Sent when damage kills entity
+#[derive(Clone, Debug)]
+pub struct DeathEvent {
+    pub entity: Entity,
+    /// Entity credited for kill
+    pub source: Option<Entity>,
+    /// Kind of lethal damage
+    pub cause: DamageKind,
+}
+
+/// Converts kinetic energy of impact into damage points
+pub const ENERGY_TO_DAMAGE: f32 = 0.000_01;
+
+/// Reduced mass of two colliding bodies. Zero or infinite mass means static body
+pub fn reduced_mass(first: f32, second: f32) -> f32 {
+    let first_static = first <= 0.0 || first.is_infinite();
+    let second_static = second <= 0.0 || second.is_infinite();
+
+    match (first_static, second_static) {
+        (true, true) => 0.0,
+        (true, false) => second,
+        (false, true) => first,
+        (false, false) => first * second / (first + second),
+    }
+}
+
+/// Kinetic energy lost in impact along contact `normal`
+pub fn impact_energy(relative_velocity: Vec2, normal: Vec2, reduced_mass: f32) -> f32 {
+    let normal_speed = relative_velocity.dot(normal.normalize_or_zero());
+    0.5 * reduced_mass * normal_speed.powi(2)
+}
+
+/// Damage of impact with `energy`. Impacts below `threshold` are ignored, `armor` absorbs flat part of damage
+pub fn collision_damage(energy: f32, threshold: f32, armor: f32) -> f32 {
+    let damage = energy * ENERGY_TO_DAMAGE;
+
+    if damage < threshold {
+        return 0.0;
+    }
+
+    (damage - armor).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn reduced_mass_of_dynamic_bodies() {
+        assert_eq!(reduced_mass(2.0, 2.0), 1.0);
+        assert_eq!(reduced_mass(3.0, 6.0), 2.0);
+    }
+
+    #[test]
+    fn reduced_mass_with_static_body() {
+        assert_eq!(reduced_mass(0.0, 5.0), 5.0);
+        assert_eq!(reduced_mass(5.0, f32::INFINITY), 5.0);
+        assert_eq!(reduced_mass(0.0, f32::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn impact_energy_uses_normal_component_only() {
+        let velocity = Vec2::new(3.0, 4.0);
+
+        assert_eq!(impact_energy(velocity, Vec2::X, 2.0), 9.0);
+        assert_eq!(impact_energy(velocity, Vec2::Y * 10.0, 2.0), 16.0);
+        assert_eq!(impact_energy(Vec2::X * 5.0, Vec2::Y, 2.0), 0.0);
+    }
+
+    #[test]
+    fn collision_damage_threshold() {
+        let energy = 100.0 / ENERGY_TO_DAMAGE;
+
+        assert_eq!(collision_damage(energy, 150.0, 0.0), 0.0);
+        assert_close(collision_damage(energy, 50.0, 0.0), 100.0);
+    }
+
+    #[test]
+    fn collision_damage_armor() {
+        let energy = 100.0 / ENERGY_TO_DAMAGE;
+
+        assert_close(collision_damage(energy, 0.0, 30.0), 70.0);
+        assert_eq!(collision_damage(energy, 0.0, 200.0), 0.0);
+    }
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use crate::components::faction::Faction;
+use crate::components::status::StatusEffect;
+
+/// Explosion event. Pushes away and damages everything in radius
+#[derive(Clone, Debug)]
+pub struct Explosion {
+    pub position: Vec2,
+    pub radius: f32,
+    /// Impulse applied to objects in epicenter
+    pub impulse: f32,
+    /// Damage dealt to objects in epicenter
+    pub damage: f32,
+    /// Entity credited for damage
+    pub source: Option<Entity>,
+    /// Faction of source, used for friendly fire rules
+    pub faction: Option<Faction>,
+    /// Effect applied to everything damaged by explosion
+    pub status: Option<StatusEffect>,
+}
+
+impl Explosion {
+    /// Returns power factor in [0, 1] of explosion at `distance` from epicenter
+    pub fn falloff(&self, distance: f32) -> f32 {
+        (1.0 - distance / self.radius).clamp(0.0, 1.0)
+    }
+
+    pub fn with_source(mut self, source: Entity, faction: Option<Faction>) -> Self {
+        self.source = Some(source);
+        self.faction = faction;
+        self
+    }
+
+    pub fn with_status(mut self, status: Option<StatusEffect>) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+/// Entity explodes when dead
+#[derive(Component, Clone)]
+pub struct Explosive {
+    radius: f32,
+    impulse: f32,
+    damage: f32,
+}
+
+impl Explosive {
+    pub fn new(radius: f32, impulse: f32, damage: f32) -> Self {
+        Self {
+            radius,
+            impulse,
+            damage,
+        }
+    }
+
+    /// Creates explosion at `position`
+    pub fn explosion(&self, position: Vec2) -> Explosion {
+        Explosion {
+            position,
+            radius: self.radius,
+            impulse: self.impulse,
+            damage: self.damage,
+            source: None,
+            faction: None,
+            status: None,
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::components::asteroid::{AsteroidMaterial, AsteroidShapeProfile};
+use crate::formation::SpawnDirector;
+use crate::random::WeightedTable;
+
+/// Side of square world chunk
+pub const CHUNK_SIZE: f32 = 1000.0;
+/// No asteroids are generated this close to world origin, where player starts
+const SAFE_RADIUS: f32 = 500.0;
+
+/// Marks asteroid generated by chunk, so chunk can remember its destruction
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ChunkAsteroid {
+    pub chunk: IVec2,
+    /// Index of asteroid in chunk generation order
+    pub index: u32,
+}
+
+/// Asteroid to be spawned by chunk
+#[derive(Clone, Debug)]
+pub struct AsteroidSpawn {
+    pub index: u32,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub size_level: i32,
+    pub material: AsteroidMaterial,
+    /// Shape of sector overriding shape of material
+    pub shape: Option<AsteroidShapeProfile>,
+    /// Seed of asteroid shape and motion
+    pub seed: u64,
+}
+
+/// Generation parameters fixed when chunk is first loaded, so reloaded chunk is the same
+#[derive(Clone, Copy, Debug)]
+pub struct ChunkParams {
+    pub count: usize,
+    pub difficulty: u32,
+    /// Point streams are aimed at, player position on first load
+    pub target: Vec2,
+}
+
+/// Streamed asteroid field. Same world seed always gives same chunks
+#[derive(Resource, Default, Debug)]
+pub struct AsteroidField {
+    loaded: HashSet<IVec2>,
+    /// Parameters of every chunk ever loaded
+    params: HashMap<IVec2, ChunkParams>,
+    /// Indices of destroyed asteroids per chunk
+    destroyed: HashMap<IVec2, HashSet<u32>>,
+}
+
+impl AsteroidField {
+    pub fn chunk_of(position: Vec2) -> IVec2 {
+        (position / CHUNK_SIZE).floor().as_ivec2()
+    }
+
+    pub fn is_loaded(&self, chunk: IVec2) -> bool {
+        self.loaded.contains(&chunk)
+    }
+
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.loaded.iter().copied()
+    }
+
+    pub fn load(&mut self, chunk: IVec2) {
+        self.loaded.insert(chunk);
+    }
+
+    /// Chunk asteroids are not despawned with chunk, they are despawned by their own position
+    pub fn unload(&mut self, chunk: IVec2) {
+        self.loaded.remove(&chunk);
+    }
+
+    /// Returns parameters of chunk remembered on first load, `params` are stored if there are none
+    pub fn chunk_params(&mut self, chunk: IVec2, params: ChunkParams) -> ChunkParams {
+        *self.params.entry(chunk).or_insert(params)
+    }
+
+    pub fn destroy(&mut self, asteroid: &ChunkAsteroid) {
+        self.destroyed
+            .entry(asteroid.chunk)
+            .or_default()
+            .insert(asteroid.index);
+    }
+
+    pub fn is_destroyed(&self, chunk: IVec2, index: u32) -> bool {
+        self.destroyed
+            .get(&chunk)
+            .is_some_and(|destroyed| destroyed.contains(&index))
+    }
+
+    pub fn reset(&mut self) {
+        self.loaded.clear();
+        self.params.clear();
+        self.destroyed.clear();
+    }
+}
+
+/// Seed of chunk derived from world seed
+pub fn chunk_seed(world_seed: u64, chunk: IVec2) -> u64 {
+    let hash = world_seed
+        ^ (chunk.x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (chunk.y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+
+    // splitmix64 finalizer
+    let hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    let hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^ (hash >> 31)
+}
+
+/// Generates asteroids of chunk. Destroyed ones are generated too, caller should skip them.
+/// Chunk contents depend on world seed and on `params` remembered when chunk was first loaded
+pub fn generate_chunk(
+    chunk: IVec2,
+    world_seed: u64,
+    params: ChunkParams,
+    director: &SpawnDirector,
+    materials: &WeightedTable<AsteroidMaterial>,
+) -> Vec<AsteroidSpawn> {
+    let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed, chunk));
+    let origin = chunk.as_vec2() * CHUNK_SIZE;
+    let distance = (origin + Vec2::splat(CHUNK_SIZE / 2.0)).length();
+
+    let mut items = director
+        .formation(distance, params.difficulty, &mut rng)
+        .generate(origin, params.count, params.target, &mut rng);
+
+    if let Some(line) = director.region_belt(world_seed, chunk) {
+        items.extend(director.belt.generate(line, origin, params.count, &mut rng));
+    }
+
+    let shape = director.region_shape(world_seed, chunk);
+
+    items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            // Values are drawn even for skipped asteroids to keep sequence stable
+            let material = materials.choose(&mut rng).copied().unwrap_or_default();
+            let seed = rng.gen();
+
+            if item.position.length() < SAFE_RADIUS {
+                return None;
+            }
+
+            Some(AsteroidSpawn {
+                index: index as u32,
+                position: item.position,
+                velocity: item.velocity,
+                size_level: item.size_level,
+                material,
+                shape: shape.clone(),
+                seed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORLD_SEED: u64 = 42;
+    const CHUNK: IVec2 = IVec2::new(5, -3);
+
+    fn params() -> ChunkParams {
+        ChunkParams {
+            count: 10,
+            difficulty: 2,
+            target: Vec2::ZERO,
+        }
+    }
+
+    fn generate(params: ChunkParams) -> Vec<AsteroidSpawn> {
+        generate_chunk(
+            CHUNK,
+            WORLD_SEED,
+            params,
+            &SpawnDirector::default(),
+            &AsteroidMaterial::spawn_table(),
+        )
+    }
+
+    fn assert_same(lhs: &AsteroidSpawn, rhs: &AsteroidSpawn) {
+        assert_eq!(lhs.index, rhs.index);
+        assert_eq!(lhs.position, rhs.position);
+        assert_eq!(lhs.velocity, rhs.velocity);
+        assert_eq!(lhs.size_level, rhs.size_level);
+        assert_eq!(lhs.material, rhs.material);
+        assert_eq!(lhs.seed, rhs.seed);
+    }
+
+    #[test]
+    fn chunk_seed_is_stable() {
+        assert_eq!(chunk_seed(WORLD_SEED, CHUNK), chunk_seed(WORLD_SEED, CHUNK));
+        assert_ne!(
+            chunk_seed(WORLD_SEED, CHUNK),
+            chunk_seed(WORLD_SEED + 1, CHUNK)
+        );
+        assert_ne!(
+            chunk_seed(WORLD_SEED, CHUNK),
+            chunk_seed(WORLD_SEED, IVec2::new(-3, 5))
+        );
+    }
+
+    #[test]
+    fn same_seed_and_params_give_same_spawns() {
+        let first = generate(params());
+        let second = generate(params());
+
+        assert!(!first.is_empty());
+        assert_eq!(first.len(), second.len());
+        for (lhs, rhs) in first.iter().zip(second.iter()) {
+            assert_same(lhs, rhs);
+        }
+    }
+
+    #[test]
+    fn chunk_params_are_remembered() {
+        let mut field = AsteroidField::default();
+        let first = field.chunk_params(CHUNK, params());
+
+        let changed = ChunkParams {
+            count: 30,
+            difficulty: 7,
+            target: Vec2::splat(1000.0),
+        };
+        let second = field.chunk_params(CHUNK, changed);
+
+        assert_eq!(first.count, second.count);
+        assert_eq!(first.difficulty, second.difficulty);
+        assert_eq!(first.target, second.target);
+    }
+
+    #[test]
+    fn destroyed_indices_stay_stable_on_regeneration() {
+        let mut field = AsteroidField::default();
+        let spawns = generate(field.chunk_params(CHUNK, params()));
+        let destroyed = spawns[spawns.len() / 2].index;
+
+        field.destroy(&ChunkAsteroid {
+            chunk: CHUNK,
+            index: destroyed,
+        });
+
+        // Chunk is reloaded later, when count and difficulty have grown
+        let changed = ChunkParams {
+            count: 30,
+            difficulty: 7,
+            target: Vec2::splat(1000.0),
+        };
+        let reloaded: Vec<_> = generate(field.chunk_params(CHUNK, changed))
+            .into_iter()
+            .filter(|spawn| !field.is_destroyed(CHUNK, spawn.index))
+            .collect();
+
+        let expected: Vec<_> = spawns
+            .iter()
+            .filter(|spawn| spawn.index != destroyed)
+            .collect();
+
+        assert_eq!(reloaded.len(), expected.len());
+        for (lhs, rhs) in reloaded.iter().zip(expected) {
+            assert_same(lhs, rhs);
+        }
+    }
+}
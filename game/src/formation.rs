@@ -0,0 +1,354 @@
+use std::f32::consts::PI;
+use std::ops::Range;
+
+use bevy::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::components::asteroid::{AsteroidShapeProfile, ShapeNoise};
+use crate::field::{chunk_seed, CHUNK_SIZE};
+use crate::random::{Deviate, WeightedTable};
+
+/// Side of region in chunks. Belts are shared by all chunks of region
+pub const REGION_SIZE: i32 = 4;
+/// Distinguishes region seeds from chunk seeds
+const REGION_SEED_SALT: u64 = 0x5E_ED0F_BE17;
+/// Distinguishes region shape seeds from region belt seeds
+const REGION_SHAPE_SALT: u64 = 0x5A_9E0F_5EC7;
+
+/// Asteroid placed by formation
+#[derive(Clone, Debug)]
+pub struct FormationItem {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub size_level: i32,
+}
+
+/// Uniformly scattered asteroids at rest
+#[derive(Clone, Debug)]
+pub struct ScatterFormation {
+    /// Multiplier of chunk asteroid count
+    pub density: f32,
+    pub size_levels: Range<i32>,
+}
+
+/// Dense groups of asteroids around few centers
+#[derive(Clone, Debug)]
+pub struct ClusterFormation {
+    pub density: f32,
+    pub clusters: Range<usize>,
+    /// Standard deviation of distance to cluster center
+    pub radius: f32,
+    pub size_levels: Range<i32>,
+}
+
+/// Long band crossing whole region. Asteroids drift together along the band
+#[derive(Clone, Debug)]
+pub struct BeltFormation {
+    pub density: f32,
+    pub width: f32,
+    pub drift_speed: f32,
+    pub size_levels: Range<i32>,
+}
+
+/// Narrow lane of small fast asteroids
+#[derive(Clone, Debug)]
+pub struct StreamFormation {
+    pub density: f32,
+    pub width: f32,
+    /// How far from target lane may pass
+    pub miss: f32,
+    pub speed: Range<f32>,
+    pub size_levels: Range<i32>,
+}
+
+/// Formation filling one chunk
+#[derive(Clone, Debug)]
+pub enum Formation {
+    Scatter(ScatterFormation),
+    Cluster(ClusterFormation),
+    Stream(StreamFormation),
+    /// Almost empty space
+    Void(ScatterFormation),
+}
+
+impl Formation {
+    /// Places asteroids in chunk with `origin` corner. `count` is base asteroid count of chunk.
+    /// Streams fly across `target`
+    pub fn generate(
+        &self,
+        origin: Vec2,
+        count: usize,
+        target: Vec2,
+        rng: &mut StdRng,
+    ) -> Vec<FormationItem> {
+        match self {
+            Self::Scatter(scatter) | Self::Void(scatter) => scatter.generate(origin, count, rng),
+            Self::Cluster(cluster) => cluster.generate(origin, count, rng),
+            Self::Stream(stream) => stream.generate(origin, count, target, rng),
+        }
+    }
+}
+
+fn scaled_count(count: usize, density: f32) -> usize {
+    (count as f32 * density).round() as usize
+}
+
+fn random_point(origin: Vec2, rng: &mut StdRng) -> Vec2 {
+    origin
+        + Vec2::new(
+            rng.gen_range(0.0..CHUNK_SIZE),
+            rng.gen_range(0.0..CHUNK_SIZE),
+        )
+}
+
+fn random_direction(rng: &mut StdRng) -> Vec2 {
+    Vec2::from_angle(rng.gen_range(0.0..2.0 * PI))
+}
+
+impl ScatterFormation {
+    fn generate(&self, origin: Vec2, count: usize, rng: &mut StdRng) -> Vec<FormationItem> {
+        (0..scaled_count(count, self.density))
+            .map(|_| FormationItem {
+                position: random_point(origin, rng),
+                velocity: Vec2::ZERO,
+                size_level: rng.gen_range(self.size_levels.clone()),
+            })
+            .collect()
+    }
+}
+
+impl ClusterFormation {
+    fn generate(&self, origin: Vec2, count: usize, rng: &mut StdRng) -> Vec<FormationItem> {
+        let centers: Vec<_> = (0..rng.gen_range(self.clusters.clone()))
+            .map(|_| random_point(origin, rng))
+            .collect();
+
+        if centers.is_empty() {
+            return Vec::new();
+        }
+
+        (0..scaled_count(count, self.density))
+            .map(|i| FormationItem {
+                position: centers[i % centers.len()].deviate(rng, self.radius as f64),
+                velocity: Vec2::ZERO,
+                size_level: rng.gen_range(self.size_levels.clone()),
+            })
+            .collect()
+    }
+}
+
+impl StreamFormation {
+    fn generate(
+        &self,
+        origin: Vec2,
+        count: usize,
+        target: Vec2,
+        rng: &mut StdRng,
+    ) -> Vec<FormationItem> {
+        // Lane starts in chunk and crosses target with small random miss
+        let center = origin + Vec2::splat(CHUNK_SIZE / 2.0);
+        let miss = rng.gen_range(-self.miss..self.miss);
+        let aim = target - center;
+        let direction = (aim + aim.perp().normalize_or_zero() * miss)
+            .try_normalize()
+            .unwrap_or_else(|| random_direction(rng));
+        let speed = rng.gen_range(self.speed.clone());
+
+        (0..scaled_count(count, self.density))
+            .map(|_| {
+                let along = rng.gen_range(-CHUNK_SIZE..CHUNK_SIZE) / 2.0;
+                let across = rng.gen_range(-self.width..self.width) / 2.0;
+
+                FormationItem {
+                    position: center + direction * along + direction.perp() * across,
+                    velocity: direction * speed,
+                    size_level: rng.gen_range(self.size_levels.clone()),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Belt line shared by chunks of one region
+#[derive(Clone, Copy, Debug)]
+pub struct BeltLine {
+    point: Vec2,
+    direction: Vec2,
+}
+
+impl BeltFormation {
+    /// Places part of belt `line` lying in chunk
+    pub fn generate(
+        &self,
+        line: BeltLine,
+        origin: Vec2,
+        count: usize,
+        rng: &mut StdRng,
+    ) -> Vec<FormationItem> {
+        let normal = line.direction.perp();
+
+        // Rejection sampling: points of chunk close enough to the line
+        let positions: Vec<_> = (0..scaled_count(count, self.density) * 4)
+            .map(|_| random_point(origin, rng))
+            .filter(|position| (*position - line.point).dot(normal).abs() < self.width / 2.0)
+            .collect();
+
+        positions
+            .into_iter()
+            .map(|position| FormationItem {
+                position,
+                velocity: line.direction * self.drift_speed,
+                size_level: rng.gen_range(self.size_levels.clone()),
+            })
+            .collect()
+    }
+}
+
+/// Chooses formations by region and difficulty
+#[derive(Resource, Clone, Debug)]
+pub struct SpawnDirector {
+    pub scatter: ScatterFormation,
+    pub cluster: ClusterFormation,
+    pub stream: StreamFormation,
+    pub void: ScatterFormation,
+    pub belt: BeltFormation,
+    /// Chance of region to have a belt
+    pub belt_chance: f64,
+    /// Regions closer to world origin are calm: no streams and belts
+    pub calm_radius: f32,
+    /// Special asteroid shapes of regions, e.g. shattered or eroded sectors.
+    /// Regions without special shape use shapes of asteroid materials
+    pub region_shapes: WeightedTable<Option<AsteroidShapeProfile>>,
+}
+
+impl Default for SpawnDirector {
+    fn default() -> Self {
+        Self {
+            scatter: ScatterFormation {
+                density: 1.0,
+                size_levels: 1..5,
+            },
+            cluster: ClusterFormation {
+                density: 2.0,
+                clusters: 1..3,
+                radius: 150.0,
+                size_levels: 1..4,
+            },
+            stream: StreamFormation {
+                density: 1.5,
+                width: 150.0,
+                miss: 300.0,
+                speed: 250.0..400.0,
+                size_levels: 1..3,
+            },
+            void: ScatterFormation {
+                density: 0.15,
+                size_levels: 1..3,
+            },
+            belt: BeltFormation {
+                density: 1.5,
+                width: 400.0,
+                drift_speed: 40.0,
+                size_levels: 2..5,
+            },
+            belt_chance: 0.3,
+            calm_radius: 3000.0,
+            region_shapes: WeightedTable::new(vec![
+                (None, 6.0),
+                // Shattered: few sharp faces
+                (
+                    Some(AsteroidShapeProfile {
+                        edges: 5..8,
+                        irregularity: 1.0,
+                        spikiness: 0.9,
+                        noise: None,
+                    }),
+                    1.0,
+                ),
+                // Eroded: round with rough outline
+                (
+                    Some(AsteroidShapeProfile {
+                        edges: 16..24,
+                        irregularity: 0.5,
+                        spikiness: 0.2,
+                        noise: Some(ShapeNoise {
+                            frequency: 5,
+                            amplitude: 0.2,
+                            octaves: 3,
+                        }),
+                    }),
+                    1.0,
+                ),
+            ]),
+        }
+    }
+}
+
+impl SpawnDirector {
+    /// Formation of chunk at `distance` from world origin. Streams get more common with difficulty
+    pub fn formation(&self, distance: f32, difficulty: u32, rng: &mut StdRng) -> Formation {
+        let calm = distance < self.calm_radius;
+        let difficulty = difficulty as f32;
+
+        let stream_weight = match calm {
+            true => 0.0,
+            false => (0.05 + 0.03 * difficulty).min(0.3),
+        };
+
+        let table = WeightedTable::new(vec![
+            (Formation::Scatter(self.scatter.clone()), 0.5),
+            (
+                Formation::Cluster(self.cluster.clone()),
+                0.25 + 0.02 * difficulty,
+            ),
+            (Formation::Stream(self.stream.clone()), stream_weight),
+            (Formation::Void(self.void.clone()), 0.15),
+        ]);
+
+        table
+            .choose(rng)
+            .cloned()
+            .unwrap_or_else(|| Formation::Scatter(self.scatter.clone()))
+    }
+
+    /// Belt line crossing region of `chunk`, if region has one
+    pub fn region_belt(&self, world_seed: u64, chunk: IVec2) -> Option<BeltLine> {
+        let region = region_of(chunk);
+        let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed ^ REGION_SEED_SALT, region));
+
+        let region_size = REGION_SIZE as f32 * CHUNK_SIZE;
+        let center = (region.as_vec2() + Vec2::splat(0.5)) * region_size;
+
+        if center.length() < self.calm_radius || !rng.gen_bool(self.belt_chance) {
+            return None;
+        }
+
+        Some(BeltLine {
+            point: center,
+            direction: random_direction(&mut rng),
+        })
+    }
+
+    /// Shape of all asteroids in region of `chunk`, if region has special one
+    pub fn region_shape(&self, world_seed: u64, chunk: IVec2) -> Option<AsteroidShapeProfile> {
+        let region = region_of(chunk);
+        let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed ^ REGION_SHAPE_SALT, region));
+
+        let region_size = REGION_SIZE as f32 * CHUNK_SIZE;
+        let center = (region.as_vec2() + Vec2::splat(0.5)) * region_size;
+
+        if center.length() < self.calm_radius {
+            return None;
+        }
+
+        self.region_shapes.choose(&mut rng).cloned().flatten()
+    }
+}
+
+/// Region of chunk
+fn region_of(chunk: IVec2) -> IVec2 {
+    IVec2::new(
+        chunk.x.div_euclid(REGION_SIZE),
+        chunk.y.div_euclid(REGION_SIZE),
+    )
+}
@@ -0,0 +1,248 @@
+use std::cmp::Reverse;
+use std::fs;
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use crate::{stats::format_duration, storage::data_file};
+
+/// Records kept in table
+pub const HIGH_SCORE_COUNT: usize = 10;
+/// Max length of player name
+pub const HIGH_SCORE_NAME_LEN: usize = 12;
+
+const HIGH_SCORE_FILE: &str = "highscores.txt";
+
+/// One finished run
+#[derive(Clone, Debug)]
+pub struct HighScore {
+    pub name: String,
+    pub points: u64,
+    /// Reached goals
+    pub goals: u32,
+    /// Run duration in seconds
+    pub duration: f32,
+    /// Unix time in seconds
+    pub date: u64,
+    pub seed: u64,
+}
+
+impl HighScore {
+    pub fn new(points: u64, goals: u32, duration: f32, seed: u64) -> Self {
+        let date = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            name: String::new(),
+            points,
+            goals,
+            duration,
+            date,
+            seed,
+        }
+    }
+
+    /// Date in YYYY-MM-DD format
+    pub fn date_string(&self) -> String {
+        // Days to civil date conversion by Howard Hinnant
+        let days = (self.date / 86_400) as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let doe = days.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    /// Duration in MM:SS format
+    pub fn duration_string(&self) -> String {
+        format_duration(self.duration)
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.points, self.goals, self.duration, self.date, self.seed, self.name
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut parts = line.splitn(6, '\t');
+
+        Some(Self {
+            points: parts.next()?.parse().ok()?,
+            goals: parts.next()?.parse().ok()?,
+            duration: parts.next()?.parse().ok()?,
+            date: parts.next()?.parse().ok()?,
+            seed: parts.next()?.parse().ok()?,
+            name: parts.next()?.to_string(),
+        })
+    }
+}
+
+/// Best runs sorted by points. Stored in platform data dir
+#[derive(Resource, Default, Clone, Debug)]
+pub struct HighScores(Vec<HighScore>);
+
+impl HighScores {
+    /// Loads table. Missing or broken file gives empty table
+    pub fn load() -> Self {
+        let Some(content) =
+            data_file(HIGH_SCORE_FILE).and_then(|path| fs::read_to_string(path).ok())
+        else {
+            return Self::default();
+        };
+
+        let mut scores: Vec<_> = content.lines().filter_map(HighScore::from_line).collect();
+        scores.sort_by_key(|score| Reverse(score.points));
+        scores.truncate(HIGH_SCORE_COUNT);
+
+        Self(scores)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = data_file(HIGH_SCORE_FILE) else {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "no data dir"));
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = fs::File::create(path)?;
+        for score in self.0.iter() {
+            writeln!(file, "{}", score.to_line())?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if run with `points` gets into table
+    pub fn qualifies(&self, points: u64) -> bool {
+        0 < points
+            && (self.0.len() < HIGH_SCORE_COUNT
+                || self.0.last().is_some_and(|last| last.points < points))
+    }
+
+    /// Inserts record and returns its place
+    pub fn insert(&mut self, score: HighScore) -> Option<usize> {
+        let place = self.0.partition_point(|other| score.points <= other.points);
+        if HIGH_SCORE_COUNT <= place {
+            return None;
+        }
+
+        self.0.insert(place, score);
+        self.0.truncate(HIGH_SCORE_COUNT);
+
+        Some(place)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HighScore> {
+        self.0.iter()
+    }
+}
+
+/// Record waiting for player name
+#[derive(Resource, Clone, Debug)]
+pub struct PendingHighScore(pub HighScore);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(points: u64) -> HighScore {
+        HighScore {
+            name: format!("P{}", points),
+            points,
+            goals: 3,
+            duration: 125.5,
+            date: 1_700_000_000,
+            seed: 7,
+        }
+    }
+
+    fn full_table() -> HighScores {
+        HighScores(
+            (1..=HIGH_SCORE_COUNT as u64)
+                .rev()
+                .map(|i| score(i * 100))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn line_round_trip() {
+        let mut original = score(1234);
+        original.name = "Ace pilot".to_string();
+
+        let parsed = HighScore::from_line(&original.to_line()).unwrap();
+
+        assert_eq!(parsed.name, original.name);
+        assert_eq!(parsed.points, original.points);
+        assert_eq!(parsed.goals, original.goals);
+        assert_eq!(parsed.duration, original.duration);
+        assert_eq!(parsed.date, original.date);
+        assert_eq!(parsed.seed, original.seed);
+    }
+
+    #[test]
+    fn broken_lines_are_skipped() {
+        assert!(HighScore::from_line("").is_none());
+        assert!(HighScore::from_line("100\t2\t30").is_none());
+        assert!(HighScore::from_line("many\t2\t30\t0\t1\tname").is_none());
+    }
+
+    #[test]
+    fn date_string_is_civil_date() {
+        assert_eq!(score(0).date_string(), "2023-11-14");
+
+        let mut epoch = score(0);
+        epoch.date = 0;
+        assert_eq!(epoch.date_string(), "1970-01-01");
+    }
+
+    #[test]
+    fn qualifies() {
+        let empty = HighScores::default();
+        assert!(!empty.qualifies(0));
+        assert!(empty.qualifies(1));
+
+        let full = full_table();
+        assert!(!full.qualifies(100));
+        assert!(full.qualifies(101));
+    }
+
+    #[test]
+    fn insert_keeps_order() {
+        let mut table = HighScores::default();
+
+        assert_eq!(table.insert(score(200)), Some(0));
+        assert_eq!(table.insert(score(300)), Some(0));
+        assert_eq!(table.insert(score(100)), Some(2));
+
+        // Equal score goes after older record
+        let mut tie = score(200);
+        tie.name = "Later".to_string();
+        assert_eq!(table.insert(tie), Some(2));
+
+        let names: Vec<_> = table.iter().map(|score| score.name.as_str()).collect();
+        assert_eq!(names, ["P300", "P200", "Later", "P100"]);
+    }
+
+    #[test]
+    fn insert_into_full_table() {
+        let mut table = full_table();
+
+        assert_eq!(table.insert(score(50)), None);
+        assert_eq!(table.insert(score(550)), Some(5));
+        assert_eq!(table.iter().count(), HIGH_SCORE_COUNT);
+        assert_eq!(table.iter().last().unwrap().points, 200);
+    }
+}
@@ -6,14 +6,31 @@ use states::GameState;
 #[macro_use]
 extern crate derive_builder;
 
+mod achievement;
 mod components;
+mod damage;
 mod entity;
+mod explosion;
+mod field;
+mod formation;
 mod goal;
+mod highscore;
 mod math;
 mod plugins;
 mod random;
+mod score;
 mod stages;
 mod states;
+mod stats;
+mod storage;
+
+/// Procedural asteroid shapes. Exposed for preview tools
+pub mod asteroid_shapes {
+    pub use crate::components::asteroid::{
+        generate_asteroid_vectors, shapes_svg, validate_polygon, AsteroidMaterial,
+        AsteroidShapeProfile, ShapeNoise,
+    };
+}
 
 pub fn run() {
     let mut app = App::new();
@@ -26,8 +43,15 @@ pub fn run() {
         .add_plugin(plugins::physics::PhysicsPlugin)
         .add_plugin(plugins::living::LivingPlugin)
         .add_plugin(plugins::despawn::DespawnPlugin)
+        .add_plugin(plugins::explosion::ExplosionPlugin)
         .add_plugin(plugins::asteroid::AsteroidsPlugin)
+        .add_plugin(plugins::resource::ResourcePlugin)
+        .add_plugin(plugins::pickup::PickupPlugin)
+        .add_plugin(plugins::comet::CometPlugin)
+        .add_plugin(plugins::boss::BossPlugin)
         .add_plugin(plugins::ship::ShipPlugin)
+        .add_plugin(plugins::shield::ShieldPlugin)
+        .add_plugin(plugins::status::StatusPlugin)
         .add_plugin(plugins::player::PlayerPlugin)
         .add_plugin(plugins::weapon::WeaponPlugin)
         .add_plugin(plugins::hud::HudPlugin)
@@ -36,6 +60,10 @@ pub fn run() {
         .add_plugin(plugins::respawn::RespawnPlugin)
         .add_plugin(plugins::particle::ParticlePlugin)
         .add_plugin(plugins::goal::GoalPlugin)
+        .add_plugin(plugins::score::ScorePlugin)
+        .add_plugin(plugins::highscore::HighScorePlugin)
+        .add_plugin(plugins::stats::StatsPlugin)
+        .add_plugin(plugins::achievement::AchievementPlugin)
         .add_startup_system(insert_marker_in_window);
 
     // Debug only components
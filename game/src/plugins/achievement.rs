@@ -0,0 +1,278 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use crate::{
+    achievement::{AchievementProgress, AchievementUnlocked, ACHIEVEMENTS},
+    components::{
+        common::{MaxTimeToLive, TimeToLive, TimeToLiveBundle},
+        ui::button::ButtonBuilder,
+    },
+    entity::{ComponentInjectorBuilder, EntityChildBuildDirector},
+    goal::Goal,
+    score::Score,
+    states::GameState,
+    stats::RunStats,
+};
+
+const ACHIEVEMENT_FONT: &str = "fonts/FiraMono-Medium.ttf";
+/// Toast lifetime in seconds
+const TOAST_TIME: f32 = 4.0;
+/// Vertical distance between stacked toasts
+const TOAST_STEP: f32 = 70.0;
+
+/// Mark for achievement unlock notification
+#[derive(Component)]
+struct AchievementToast;
+
+/// Mark for achievements page root
+#[derive(Component)]
+struct AchievementsPage;
+
+/// Mark for button opening achievements page. Used by menus
+#[derive(Component, Clone)]
+pub struct AchievementsButton;
+
+/// Mark for button closing achievements page
+#[derive(Component, Clone)]
+struct AchievementsBackButton;
+
+pub struct AchievementPlugin;
+
+impl Plugin for AchievementPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AchievementProgress::load())
+            .add_event::<AchievementUnlocked>()
+            .add_systems(
+                (achievement_check, achievement_toast_spawn)
+                    .chain()
+                    .in_set(OnUpdate(GameState::InGame)),
+            )
+            .add_systems((
+                achievement_toast_update,
+                on_achievements_pressed,
+                on_achievements_back_pressed,
+            ))
+            .add_system(achievements_page_despawn.in_schedule(OnExit(GameState::Pause)))
+            .add_system(achievements_page_despawn.in_schedule(OnExit(GameState::Respawn)));
+    }
+}
+
+fn achievement_check(
+    mut progress: ResMut<AchievementProgress>,
+    stats: Res<RunStats>,
+    score: Res<Score>,
+    goal: Res<Goal>,
+    mut ev_unlocked: EventWriter<AchievementUnlocked>,
+) {
+    let mut unlocked_any = false;
+
+    for achievement in ACHIEVEMENTS.iter() {
+        if progress.unlocked(achievement) || !achievement.condition.satisfied(&stats, &score, &goal)
+        {
+            continue;
+        }
+
+        progress.unlock(achievement);
+        ev_unlocked.send(AchievementUnlocked(achievement));
+        unlocked_any = true;
+    }
+
+    if unlocked_any {
+        if let Err(err) = progress.save() {
+            warn!("Failed to save achievements: {}", err);
+        }
+    }
+}
+
+fn achievement_toast_spawn(
+    mut commands: Commands,
+    mut ev_unlocked: EventReader<AchievementUnlocked>,
+    q_toasts: Query<(), With<AchievementToast>>,
+    asset_server: Res<AssetServer>,
+) {
+    // New toasts are stacked under shown ones
+    let shown = q_toasts.iter().count();
+
+    for (i, ev) in ev_unlocked.iter().enumerate() {
+        let font = asset_server.load(ACHIEVEMENT_FONT);
+
+        commands
+            .spawn(
+                TextBundle::from_sections([
+                    TextSection::new(
+                        format!("Achievement unlocked: {}\n", ev.0.name),
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 26.0,
+                            color: Color::GOLD,
+                        },
+                    ),
+                    TextSection::new(
+                        ev.0.description,
+                        TextStyle {
+                            font,
+                            font_size: 20.0,
+                            color: Color::WHITE,
+                        },
+                    ),
+                ])
+                .with_style(Style {
+                    position: UiRect {
+                        top: Val::Px(20.0 + TOAST_STEP * (shown + i) as f32),
+                        left: Val::Percent(35.0),
+                        ..Default::default()
+                    },
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                }),
+            )
+            .insert(TimeToLiveBundle::new(TOAST_TIME))
+            .insert(AchievementToast);
+    }
+}
+
+/// Toasts fade out at the end of their life
+fn achievement_toast_update(
+    mut q_toasts: Query<(&mut Text, &TimeToLive, &MaxTimeToLive), With<AchievementToast>>,
+) {
+    for (mut text, time_to_live, max_time_to_live) in q_toasts.iter_mut() {
+        let alpha = (2.0 * time_to_live.value() / max_time_to_live.max()).clamp(0.0, 1.0);
+        for section in text.sections.iter_mut() {
+            section.style.color.set_a(alpha);
+        }
+    }
+}
+
+fn achievements_text(progress: &AchievementProgress) -> String {
+    let mut content = format!(
+        "ACHIEVEMENTS {}/{}\n\n",
+        progress.count(),
+        ACHIEVEMENTS.len()
+    );
+
+    for achievement in ACHIEVEMENTS.iter() {
+        let mark = if progress.unlocked(achievement) {
+            "[x]"
+        } else {
+            "[ ]"
+        };
+
+        content += &format!(
+            "{} {:<14} {}\n",
+            mark, achievement.name, achievement.description
+        );
+    }
+
+    content
+}
+
+fn on_achievements_pressed(
+    mut commands: Commands,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<AchievementsButton>)>,
+    q_pages: Query<(), With<AchievementsPage>>,
+    progress: Res<AchievementProgress>,
+    asset_server: Res<AssetServer>,
+) {
+    let clicked = button_query
+        .iter()
+        .any(|interaction| *interaction == Interaction::Clicked);
+
+    if !clicked || !q_pages.is_empty() {
+        return;
+    }
+
+    let font = asset_server.load(ACHIEVEMENT_FONT);
+
+    let mut back_button_builder = ButtonBuilder::default();
+    back_button_builder
+        .text("Back".to_string())
+        .font(font.clone())
+        .style(Style {
+            size: Size::new(Val::Percent(30.0), Val::Percent(10.0)),
+            margin: UiRect {
+                left: Val::Auto,
+                right: Val::Auto,
+                bottom: Val::Px(20.0),
+                ..Default::default()
+            },
+            justify_content: JustifyContent::Center,
+            align_content: AlignContent::Center,
+            ..Default::default()
+        });
+
+    let back_button_builder =
+        ComponentInjectorBuilder::new(back_button_builder, AchievementsBackButton);
+
+    commands
+        .spawn(AchievementsPage)
+        .insert(NodeBundle {
+            style: Style {
+                size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                position_type: PositionType::Absolute,
+                position: UiRect {
+                    bottom: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                },
+                align_content: AlignContent::Center,
+                flex_direction: FlexDirection::Column,
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+
+                ..Default::default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.95).into(),
+            // Menu below must not receive clicks
+            focus_policy: FocusPolicy::Block,
+            ..Default::default()
+        })
+        .with_children(|cs| {
+            cs.spawn(
+                TextBundle::from_section(
+                    achievements_text(&progress),
+                    TextStyle {
+                        font,
+                        font_size: 20.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect {
+                        left: Val::Auto,
+                        right: Val::Auto,
+                        bottom: Val::Px(30.0),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+            );
+        })
+        .build_child_entity(&back_button_builder);
+}
+
+fn on_achievements_back_pressed(
+    mut commands: Commands,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<AchievementsBackButton>)>,
+    q_pages: Query<Entity, With<AchievementsPage>>,
+) {
+    let clicked = button_query
+        .iter()
+        .any(|interaction| *interaction == Interaction::Clicked);
+
+    if !clicked {
+        return;
+    }
+
+    for entity in q_pages.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn achievements_page_despawn(
+    mut commands: Commands,
+    q_pages: Query<Entity, With<AchievementsPage>>,
+) {
+    for entity in q_pages.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,37 +1,56 @@
+use std::collections::HashSet;
 use std::f32::consts::PI;
 
-use bevy::prelude::*;
-use bevy_rapier2d::prelude::Velocity;
-use physic_objects::PhysicObjectBundle;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_rapier2d::prelude::{ReadMassProperties, Velocity};
+use physic_objects::{Area, PhysicObjectBundle};
 use rand::Rng;
 
 use crate::{
     components::{
-        asteroid::{Asteroid, AsteroidBuilder, AsteroidSizeLevel},
-        common::Despawn,
-        health::Dead,
+        asteroid::{Asteroid, AsteroidBuilder, AsteroidMaterial, AsteroidSizeLevel, BossAsteroid},
+        camera::MainCamera,
+        common::{Despawn, DespawnOnOutOfRange},
+        health::{Dead, FatalHit},
+        pickup::{PickupBuilder, PickupKind},
         player::Player,
+        resource::ResourceFragmentBuilder,
         ui::MainWindow,
     },
+    damage::DeathEvent,
     entity::EntityBuildDirector,
-    goal::GoalChangeEvent,
+    field::{generate_chunk, AsteroidField, ChunkAsteroid, ChunkParams, CHUNK_SIZE},
+    formation::SpawnDirector,
+    goal::{Goal, GoalChangeEvent},
     math::Position,
-    random::Deviate,
+    random::{Deviate, RunSeed, WeightedTable},
+    score::{Score, ScoreEvent},
     stages::LivingStages,
     states::GameState,
 };
 
-/// Minimal range from screen border to spawn asteroid
-const MIN_SPAWN_RANGE: f32 = 200.0;
-/// Maximum range from screen border to spawn asteroid
-const MAX_SPAWN_RANGE: f32 = 1200.0;
+/// Chunks are loaded this far beyond screen corners
+const CHUNK_LOAD_MARGIN: f32 = 500.0;
 
-/// Starter target asteroid count
-const ASTEROID_COUNT_START: usize = 100;
+/// Speed deviation of resource fragments dropped by asteroid
+const RESOURCE_SPEED_DEVIATION: f64 = 30.0;
+
+/// Speed deviation of pickups dropped by asteroid
+const PICKUP_SPEED_DEVIATION: f64 = 20.0;
+
+/// Speed of fragments relative to destroyed asteroid
+const FRAGMENT_SPEED: f32 = 50.0;
+/// Speed of fragments in direction of the killing blow
+const FRAGMENT_IMPACT_SPEED: f32 = 80.0;
+/// Extra speed of fragments on the far side from the hit point
+const FRAGMENT_SPALL_SPEED: f32 = 40.0;
+
+/// Starter asteroid count in chunk
+const ASTEROID_COUNT_START: usize = 8;
 /// How fast asteroid count increasing depending on score
-const ASTEROID_COUNT_STEP: usize = 50;
+const ASTEROID_COUNT_STEP: usize = 4;
 
-/// Target Asteroid count in world
+/// Asteroid count in newly loaded chunk
 #[derive(Resource)]
 pub struct AsteroidCount(usize);
 
@@ -47,66 +66,238 @@ impl Default for AsteroidCount {
     }
 }
 
+/// Materials of asteroids spawned in open space
+#[derive(Resource)]
+pub struct AsteroidMaterialTable(WeightedTable<AsteroidMaterial>);
+
+impl Default for AsteroidMaterialTable {
+    fn default() -> Self {
+        Self(AsteroidMaterial::spawn_table())
+    }
+}
+
+/// Pickup drop tables by asteroid size level, starting from first level
+#[derive(Resource)]
+pub struct PickupDropTables(Vec<WeightedTable<Option<PickupKind>>>);
+
+impl PickupDropTables {
+    /// Bigger asteroids use table of the biggest level
+    pub fn table(&self, size_level: i32) -> &WeightedTable<Option<PickupKind>> {
+        let index = (size_level.max(1) as usize - 1).min(self.0.len() - 1);
+        &self.0[index]
+    }
+}
+
+impl Default for PickupDropTables {
+    fn default() -> Self {
+        Self((1..=4).map(PickupKind::drop_table).collect())
+    }
+}
+
 pub struct AsteroidsPlugin;
 
 impl Plugin for AsteroidsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<AsteroidCount>().add_systems((
-            asteroids_spawn_system.in_set(OnUpdate(GameState::InGame)),
-            asteroid_dead.in_set(LivingStages::DeadProcessing),
-            asteroid_count_increase.in_set(OnUpdate(GameState::InGame)),
-            asteroid_count_reset.in_schedule(OnExit(GameState::Respawn)),
-        ));
+        app.init_resource::<AsteroidCount>()
+            .init_resource::<AsteroidMaterialTable>()
+            .init_resource::<PickupDropTables>()
+            .init_resource::<AsteroidField>()
+            .init_resource::<SpawnDirector>()
+            .add_systems((
+                asteroid_chunks_stream.in_set(OnUpdate(GameState::InGame)),
+                asteroid_field_reset.in_schedule(OnExit(GameState::Respawn)),
+                asteroid_count_increase.in_set(OnUpdate(GameState::InGame)),
+                asteroid_count_reset.in_schedule(OnExit(GameState::Respawn)),
+            ))
+            // Kill is scored while dead asteroid still has its body
+            .add_systems(
+                (asteroid_score, asteroid_dead)
+                    .chain()
+                    .in_set(LivingStages::DeadProcessing),
+            );
     }
 }
 
-/// Spawn asteroids
-fn asteroids_spawn_system(
+/// Read-only resources chunk generation depends on
+#[derive(SystemParam)]
+struct ChunkGeneration<'w> {
+    asteroids_count: Res<'w, AsteroidCount>,
+    material_table: Res<'w, AsteroidMaterialTable>,
+    seed: Res<'w, RunSeed>,
+    goal: Res<'w, Goal>,
+    director: Res<'w, SpawnDirector>,
+}
+
+/// Loads asteroid chunks around camera and unloads far ones
+fn asteroid_chunks_stream(
     mut commands: Commands,
     window: Query<&Window, With<MainWindow>>,
-    asteroids_count: Res<AsteroidCount>,
-    asteroids: Query<(), With<Asteroid>>,
-    player: Query<&Transform, With<Player>>,
+    camera: Query<&Transform, With<MainCamera>>,
+    q_player: Query<&Transform, (With<Player>, Without<MainCamera>)>,
+    q_chunk_asteroids: Query<(&ChunkAsteroid, &Transform, Entity), Without<MainCamera>>,
+    generation: ChunkGeneration,
+    mut field: ResMut<AsteroidField>,
 ) {
-    let window = window.single();
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let Ok(camera) = camera.get_single() else {
+        return;
+    };
 
-    let radius_w = window.width() / 2.0;
-    let radius_h = window.width() / 2.0;
-    let radius_diagonal = (radius_w.powi(2) + radius_h.powi(2)).sqrt();
+    let view_radius = (window.width().powi(2) + window.height().powi(2)).sqrt() / 2.0;
+    let load_range = ((view_radius + CHUNK_LOAD_MARGIN) / CHUNK_SIZE).ceil() as i32;
+    let center = AsteroidField::chunk_of(camera.position());
 
-    let asteroid_spawned = asteroids.iter().count();
-    let mut rng = rand::thread_rng();
+    // One extra chunk before unloading, so chunks on the border do not reload every frame
+    let far_chunks: Vec<_> = field
+        .loaded_chunks()
+        .filter(|chunk| load_range + 1 < (*chunk - center).abs().max_element())
+        .collect();
 
-    let player_position = player.single().position();
+    for chunk in far_chunks {
+        field.unload(chunk);
+    }
 
-    if asteroid_spawned < asteroids_count.0 {
-        let center_position = player_position;
+    // Asteroids are owned by chunk they are in now, not by chunk that spawned them.
+    // Ones in far chunks are gone, not destroyed ones come back to their place when
+    // their chunk is loaded again. Ones drifted near camera stay and are not spawned twice
+    let mut alive = HashSet::new();
+    for (chunk_asteroid, transform, entity) in q_chunk_asteroids.iter() {
+        let chunk = AsteroidField::chunk_of(transform.position());
+        if load_range + 1 < (chunk - center).abs().max_element() {
+            commands.entity(entity).insert(Despawn::Recursive);
+            continue;
+        }
 
-        let range_from_border = rng.gen_range(MIN_SPAWN_RANGE..MAX_SPAWN_RANGE);
-        let angle: f32 = rng.gen_range(0.0..(2.0 * PI));
-        let position = Quat::from_rotation_z(angle).mul_vec3(Vec3::Y).truncate()
-            * (radius_diagonal + range_from_border);
+        alive.insert((chunk_asteroid.chunk, chunk_asteroid.index));
+    }
 
-        let size_level = rng.gen_range(1..5);
+    let target = q_player
+        .get_single()
+        .map_or(camera.position(), |player| player.position());
 
-        let mut builder = AsteroidBuilder::default();
-        let created_entity = commands.build_entity(
-            builder
-                .position(position + center_position)
-                .size_level(size_level)
-                .base_velocity(Vec2::ZERO),
-        );
+    for x in -load_range..=load_range {
+        for y in -load_range..=load_range {
+            let chunk = center + IVec2::new(x, y);
+            if field.is_loaded(chunk) {
+                continue;
+            }
 
-        commands.entity(created_entity);
+            // Chunk keeps contents of its first load, so destroyed indices stay valid
+            let params = field.chunk_params(
+                chunk,
+                ChunkParams {
+                    count: generation.asteroids_count.0,
+                    difficulty: generation.goal.points,
+                    target,
+                },
+            );
+
+            let spawns = generate_chunk(
+                chunk,
+                generation.seed.seed(),
+                params,
+                &generation.director,
+                &generation.material_table.0,
+            );
+
+            let spawns = spawns
+                .into_iter()
+                .filter(|spawn| !field.is_destroyed(chunk, spawn.index))
+                .filter(|spawn| !alive.contains(&(chunk, spawn.index)));
+
+            for spawn in spawns {
+                let mut builder = AsteroidBuilder::default();
+                builder
+                    .position(spawn.position)
+                    .base_velocity(spawn.velocity)
+                    .size_level(spawn.size_level)
+                    .material(spawn.material)
+                    .seed(spawn.seed);
+
+                if let Some(shape) = spawn.shape {
+                    builder.shape(shape);
+                }
+
+                let entity = commands.build_entity(&builder);
+
+                // Chunk asteroids live as long as chunk they are in is loaded
+                commands
+                    .entity(entity)
+                    .remove::<DespawnOnOutOfRange>()
+                    .insert(ChunkAsteroid {
+                        chunk,
+                        index: spawn.index,
+                    });
+            }
+
+            field.load(chunk);
+        }
     }
 }
 
+fn asteroid_field_reset(mut field: ResMut<AsteroidField>) {
+    field.reset();
+}
+
+/// Rewards depend on real mass, thin sliver is worth less than boulder of same level.
+/// Mass is not read back from physics yet if asteroid dies right after spawn
+fn mass_level(material: &AsteroidMaterial, area: &Area, mass: &ReadMassProperties) -> f32 {
+    let mass = match mass.0.mass {
+        mass if 0.0 < mass => mass,
+        _ => area.area() * material.density(),
+    };
+
+    AsteroidSizeLevel::mass_level(mass)
+}
+
+/// Asteroids except bosses, which are handled by boss plugin
+type PlainAsteroid = (With<Asteroid>, Without<BossAsteroid>);
+
+/// Scores asteroids killed by player. Bosses are scored by boss plugin
+fn asteroid_score(
+    mut ev_death: EventReader<DeathEvent>,
+    q_asteroids: Query<(&AsteroidMaterial, &Area, &ReadMassProperties, &Transform), PlainAsteroid>,
+    q_player: Query<Entity, With<Player>>,
+    mut score: ResMut<Score>,
+    mut ev_score: EventWriter<ScoreEvent>,
+) {
+    let player = q_player.get_single().ok();
+
+    for death in ev_death.iter() {
+        if player.is_none() || death.source != player {
+            continue;
+        }
+
+        let Ok((material, area, mass, transform)) = q_asteroids.get(death.entity) else {
+            continue;
+        };
+
+        let points = score.asteroid_destroyed(mass_level(material, area, mass));
+        ev_score.send(ScoreEvent::new(points, transform.position()));
+    }
+}
+
+/// Dead asteroid with everything its fragments are made of
+type DeadAsteroid = (
+    &'static AsteroidSizeLevel,
+    &'static AsteroidMaterial,
+    &'static Transform,
+    &'static Velocity,
+    &'static Area,
+    &'static ReadMassProperties,
+    Option<&'static FatalHit>,
+    Option<&'static ChunkAsteroid>,
+    Entity,
+);
+
+/// Bosses are broken apart by boss plugin
 fn asteroid_dead(
     mut commands: Commands,
-    q_deads: Query<
-        (&AsteroidSizeLevel, &Transform, &Velocity, Entity),
-        (With<Asteroid>, With<Dead>),
-    >,
+    q_deads: Query<DeadAsteroid, (PlainAsteroid, With<Dead>)>,
+    mut field: ResMut<AsteroidField>,
+    drop_tables: Res<PickupDropTables>,
 ) {
     if q_deads.is_empty() {
         return;
@@ -114,43 +305,113 @@ fn asteroid_dead(
 
     let mut rng = rand::thread_rng();
 
-    for (size, transform, parent_velocity, entity) in q_deads.iter() {
+    for (
+        size,
+        material,
+        transform,
+        parent_velocity,
+        area,
+        mass,
+        fatal_hit,
+        chunk_asteroid,
+        entity,
+    ) in q_deads.iter()
+    {
         commands
             .entity(entity)
             .remove::<PhysicObjectBundle>()
-            .insert(Despawn::Normal);
+            .insert(Despawn::Recursive);
+
+        // Destroyed asteroid does not come back when its chunk is reloaded
+        if let Some(chunk_asteroid) = chunk_asteroid {
+            field.destroy(chunk_asteroid);
+        }
 
-        // Do not create zero sized asteroids
-        if size.level() <= 1 {
+        let parent_area = area.area();
+        let drop_level = mass_level(material, area, mass).round() as i32;
+        let radius = (parent_area / PI).sqrt();
+
+        for _ in 0..material.resource_drop(drop_level) {
+            let velocity = Vec2::ZERO.deviate(&mut rng, RESOURCE_SPEED_DEVIATION);
+
+            let mut builder = ResourceFragmentBuilder::default();
+            builder
+                .position(transform.position() + velocity.normalize_or_zero() * radius / 2.0)
+                .velocity(parent_velocity.linvel + velocity);
+
+            commands.build_entity(&builder);
+        }
+
+        if let Some(Some(kind)) = drop_tables.table(drop_level).choose(&mut rng) {
+            let velocity = Vec2::ZERO.deviate(&mut rng, PICKUP_SPEED_DEVIATION);
+
+            let mut builder = PickupBuilder::default();
+            builder
+                .position(transform.position())
+                .velocity(parent_velocity.linvel + velocity)
+                .kind(*kind);
+
+            commands.build_entity(&builder);
+        }
+
+        // Do not create zero sized asteroids. Sliver smaller than one shard does not split
+        let shard_level = material.shard_level(size.level());
+        let shard_fit = parent_area / AsteroidSizeLevel::new(shard_level.max(1)).typical_area();
+        if size.level() <= 1 || shard_fit < 1.0 {
             continue;
         }
 
-        let shard_count = rng.gen_range(2..=4);
+        // Count of shards follows how many typical shards fit into parent area
+        let split_count = material.split_count();
+        let shard_count = ((shard_fit * rng.gen_range(0.6..1.0)).round() as usize)
+            .clamp(*split_count.start(), *split_count.end());
 
         let mut velocity_angle = 0.0;
         let velocity_angle_step = 2.0 * PI / shard_count as f32;
         let velocity_angle_deviation = 2.0 * PI / shard_count as f32 / 3.0; // 3.0 - is 3 sigma rule for normal distribution
 
-        for _ in 0..shard_count {
-            //
+        // Whole debris cloud is pushed in direction of the killing blow
+        let impact_velocity =
+            fatal_hit.map_or(Vec2::ZERO, |hit| hit.direction() * FRAGMENT_IMPACT_SPEED);
+        // Fragments facing away from the hit point fly off faster
+        let away_from_hit = fatal_hit.map_or(Vec2::ZERO, |hit| {
+            (transform.position() - hit.point()).normalize_or_zero()
+        });
+
+        // Parent area is split between fragments in random proportions
+        let weights: Vec<f32> = (0..shard_count).map(|_| rng.gen_range(0.5..1.5)).collect();
+        let weights_sum: f32 = weights.iter().sum();
+
+        for weight in weights {
             let mutated_velocity_angle =
                 velocity_angle.deviate(&mut rng, velocity_angle_deviation as f64);
-            let velocity = Quat::from_rotation_z(mutated_velocity_angle)
+            let direction = Quat::from_rotation_z(mutated_velocity_angle)
                 .mul_vec3(Vec3::Y)
-                .truncate()
-                * 50.0;
-            let position = transform.translation.truncate();
-            let size_level = size.level() - 1;
+                .truncate();
+            let offset = direction * radius / 1.5;
+
+            let spall = direction.dot(away_from_hit).max(0.0) * FRAGMENT_SPALL_SPEED;
+
+            // Rotating parent gives fragments tangential velocity
+            let velocity = parent_velocity.linvel
+                + offset.perp() * parent_velocity.angvel
+                + direction * (FRAGMENT_SPEED + spall)
+                + impact_velocity;
 
             let mut builder = AsteroidBuilder::default();
             builder
-                .position(position + velocity.normalize() * size.typical_radius() / 1.5)
-                .size_level(size_level)
-                .base_velocity(parent_velocity.linvel);
+                .position(transform.position() + offset)
+                .size_level(shard_level)
+                .base_velocity(velocity)
+                .base_angular_velocity(parent_velocity.angvel)
+                .material(material.shard_material());
+
+            if 0.0 < parent_area {
+                builder.area(parent_area * weight / weights_sum);
+            }
 
             commands.build_entity(&builder);
 
-            //
             velocity_angle += velocity_angle_step;
         }
     }
@@ -0,0 +1,402 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use physic_objects::PhysicObjectBundle;
+use rand::Rng;
+
+use crate::{
+    components::{
+        asteroid::{
+            ArmorSegment, AsteroidBuilder, AsteroidMaterial, AsteroidSizeLevel, BossArmored,
+            BossAsteroid, BossAsteroidBuilder,
+        },
+        common::Despawn,
+        health::{Dead, Health, MaxHealth, Resistances},
+        pickup::{PickupBuilder, PickupKind},
+        player::Player,
+        resource::ResourceFragmentBuilder,
+        ui::progressbar::*,
+    },
+    damage::DeathEvent,
+    entity::{ComponentInjectorBuilder, EntityBuildDirector},
+    goal::{Goal, GoalChangeEvent},
+    math::{Position, RotateAroundZ},
+    random::Deviate,
+    score::{Score, ScoreEvent},
+    stages::LivingStages,
+    states::GameState,
+};
+
+/// Boss appears every time this count of goals is reached
+const BOSS_GOAL_STEP: u32 = 3;
+const BOSS_MIN_LEVEL: i32 = 6;
+const BOSS_MAX_LEVEL: i32 = 7;
+/// Boss guards new goal at this distance from it
+const BOSS_GOAL_OFFSET: f32 = 1200.0;
+
+/// Count of small asteroids shed at once
+const BOSS_SHED_COUNT: usize = 3;
+const BOSS_SHED_SPEED: f32 = 120.0;
+
+/// Beaten boss breaks into this many asteroids two levels smaller
+const BOSS_SHARD_COUNT: usize = 5;
+const BOSS_SHARD_SPEED: f32 = 60.0;
+
+/// Bonus points for beaten boss per its size level
+const BOSS_SCORE_BONUS: u64 = 2000;
+/// Reward fragments dropped by beaten boss
+const BOSS_REWARD_FRAGMENTS: usize = 8;
+const BOSS_REWARD_AMOUNT: u32 = 5;
+const BOSS_REWARD_SPEED_DEVIATION: f64 = 60.0;
+
+/// Boss health is shown and boss sheds asteroids when player is this close to it
+const BOSS_HUD_RANGE: f32 = 3000.0;
+
+/// Filter of alive entities with `T`
+type Alive<T> = (With<T>, Without<Dead>);
+/// Filter of killed entities with `T`
+type Killed<T> = (With<T>, With<Dead>);
+/// Values and visibility of boss progress bar
+type BossBarValues = (
+    &'static mut Value,
+    &'static mut MaxValue,
+    &'static mut Visibility,
+);
+
+/// Health bar of the nearest boss
+#[derive(Component, Clone)]
+struct BossHP;
+
+/// Remaining armour of the nearest boss
+#[derive(Component, Clone)]
+struct BossArmor;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(create_boss_bars)
+            .add_systems((
+                boss_spawn.in_set(OnUpdate(GameState::InGame)),
+                boss_shed.in_set(OnUpdate(GameState::InGame)),
+                boss_despawn_passed.in_set(OnUpdate(GameState::InGame)),
+                boss_armor_update.in_set(OnUpdate(GameState::InGame)),
+                boss_bars_update.in_set(OnUpdate(GameState::InGame)),
+                armor_segment_dead.in_set(LivingStages::DeadProcessing),
+            ))
+            .add_systems(
+                (boss_score, boss_dead)
+                    .chain()
+                    .in_set(LivingStages::DeadProcessing),
+            );
+    }
+}
+
+fn create_boss_bar_builder(top: Val, height: Val, color: Color) -> ProgressBarBuilder {
+    let mut progress_bar_builder = ProgressBarBuilder::default();
+    progress_bar_builder
+        .min(0.0)
+        .max(100.0)
+        .style(Style {
+            size: Size::new(Val::Percent(40.0), height),
+            position: UiRect {
+                top,
+                left: Val::Percent(30.0),
+                ..Default::default()
+            },
+            position_type: PositionType::Absolute,
+            ..Default::default()
+        })
+        .color_front(color)
+        .color_back(Color::DARK_GRAY);
+
+    progress_bar_builder
+}
+
+fn create_boss_bars(mut commands: Commands) {
+    let hp_builder = ComponentInjectorBuilder::new(
+        create_boss_bar_builder(Val::Px(10.0), Val::Percent(2.0), Color::PURPLE),
+        BossHP,
+    );
+    let armor_builder = ComponentInjectorBuilder::new(
+        create_boss_bar_builder(Val::Percent(3.0), Val::Percent(1.0), Color::SILVER),
+        BossArmor,
+    );
+
+    for entity in [
+        commands.build_entity(&hp_builder),
+        commands.build_entity(&armor_builder),
+    ] {
+        commands.entity(entity).insert(Visibility::Hidden);
+    }
+}
+
+/// Boss guards every [BOSS_GOAL_STEP]th goal
+fn boss_spawn(mut commands: Commands, mut ev_goal: EventReader<GoalChangeEvent>) {
+    let Some(goal) = ev_goal.iter().last() else {
+        return;
+    };
+
+    if goal.points() == 0 || goal.points() % BOSS_GOAL_STEP != 0 {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+
+    let level = (BOSS_MIN_LEVEL + (goal.points() / BOSS_GOAL_STEP) as i32 - 1).min(BOSS_MAX_LEVEL);
+    let offset = Vec2::X.rotate_z(rng.gen_range(-PI..PI)) * BOSS_GOAL_OFFSET;
+
+    commands.build_entity(
+        BossAsteroidBuilder::default()
+            .position(*goal.position() + offset)
+            .size_level(level)
+            .guarded_goal(goal.points()),
+    );
+
+    info!("Boss asteroid of level {} appeared", level);
+}
+
+/// Boss left behind after its goal is reached is despawned once out of range
+fn boss_despawn_passed(
+    mut commands: Commands,
+    goal: Res<Goal>,
+    q_player: Query<&Transform, With<Player>>,
+    q_bosses: Query<(&BossAsteroid, &Transform, Entity), Without<Dead>>,
+) {
+    let Ok(player_transform) = q_player.get_single() else {
+        return;
+    };
+
+    for (boss, transform, entity) in q_bosses.iter() {
+        let passed = boss.guarded_goal() < goal.points;
+        let distance = transform.position().distance(player_transform.position());
+
+        if passed && BOSS_HUD_RANGE < distance {
+            commands.entity(entity).insert(Despawn::Recursive);
+        }
+    }
+}
+
+/// Boss periodically sheds small asteroids around itself while player is in range
+fn boss_shed(
+    mut commands: Commands,
+    time: Res<Time>,
+    q_player: Query<&Transform, With<Player>>,
+    mut q_bosses: Query<
+        (&mut BossAsteroid, &AsteroidSizeLevel, &Transform, &Velocity),
+        Without<Dead>,
+    >,
+) {
+    let Ok(player_transform) = q_player.get_single() else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+
+    for (mut boss, level, transform, velocity) in q_bosses.iter_mut() {
+        let distance = transform.position().distance(player_transform.position());
+        if BOSS_HUD_RANGE < distance {
+            continue;
+        }
+
+        if !boss.update(time.delta()) {
+            continue;
+        }
+
+        for _ in 0..BOSS_SHED_COUNT {
+            let direction = Vec2::X.rotate_z(rng.gen_range(-PI..PI));
+            let size_level = rng.gen_range(1..=2);
+            let distance =
+                level.typical_radius() + AsteroidSizeLevel::new(size_level).typical_radius();
+
+            let mut builder = AsteroidBuilder::default();
+            builder
+                .position(transform.position() + direction * distance * 1.2)
+                .size_level(size_level)
+                .base_velocity(velocity.linvel + direction * BOSS_SHED_SPEED);
+
+            commands.build_entity(&builder);
+        }
+    }
+}
+
+/// Boss becomes vulnerable when all its armour segments are broken off
+fn boss_armor_update(
+    mut commands: Commands,
+    q_bosses: Query<Entity, (With<BossAsteroid>, With<BossArmored>)>,
+    q_segments: Query<&Parent, (With<ArmorSegment>, Without<Dead>)>,
+) {
+    for boss in q_bosses.iter() {
+        if q_segments.iter().any(|parent| parent.get() == boss) {
+            continue;
+        }
+
+        commands
+            .entity(boss)
+            .remove::<BossArmored>()
+            .remove::<Resistances>();
+    }
+}
+
+/// Broken off segment flies away as small metal asteroid
+fn armor_segment_dead(
+    mut commands: Commands,
+    q_segments: Query<(&GlobalTransform, &Parent, Entity), Killed<ArmorSegment>>,
+    q_bosses: Query<(&Transform, &Velocity), With<BossAsteroid>>,
+) {
+    for (transform, parent, entity) in q_segments.iter() {
+        commands.entity(entity).insert(Despawn::Recursive);
+
+        let Ok((boss_transform, boss_velocity)) = q_bosses.get(parent.get()) else {
+            continue;
+        };
+
+        let position = transform.translation().truncate();
+        let direction = (position - boss_transform.position()).normalize_or_zero();
+
+        let mut builder = AsteroidBuilder::default();
+        builder
+            .position(position + direction * AsteroidSizeLevel::new(1).typical_radius())
+            .size_level(1)
+            .base_velocity(boss_velocity.linvel + direction * BOSS_SHED_SPEED)
+            .material(AsteroidMaterial::Metal);
+
+        commands.build_entity(&builder);
+    }
+}
+
+/// Gives bonus for boss beaten by player.
+/// Boss is skipped by usual asteroid scoring, so it is scored only here
+fn boss_score(
+    mut ev_death: EventReader<DeathEvent>,
+    q_bosses: Query<(&AsteroidSizeLevel, &Transform), With<BossAsteroid>>,
+    q_player: Query<Entity, With<Player>>,
+    mut score: ResMut<Score>,
+    mut ev_score: EventWriter<ScoreEvent>,
+) {
+    let player = q_player.get_single().ok();
+
+    for death in ev_death.iter() {
+        if player.is_none() || death.source != player {
+            continue;
+        }
+
+        let Ok((level, transform)) = q_bosses.get(death.entity) else {
+            continue;
+        };
+
+        let points = score.add(BOSS_SCORE_BONUS * level.level() as u64);
+        ev_score.send(ScoreEvent::new(points, transform.position()));
+    }
+}
+
+/// Beaten boss breaks apart and always drops reward
+fn boss_dead(
+    mut commands: Commands,
+    q_bosses: Query<(&AsteroidSizeLevel, &Transform, &Velocity, Entity), Killed<BossAsteroid>>,
+) {
+    let mut rng = rand::thread_rng();
+
+    for (level, transform, velocity, entity) in q_bosses.iter() {
+        commands
+            .entity(entity)
+            .remove::<PhysicObjectBundle>()
+            .insert(Despawn::Recursive);
+
+        for _ in 0..BOSS_REWARD_FRAGMENTS {
+            let speed = Vec2::ZERO.deviate(&mut rng, BOSS_REWARD_SPEED_DEVIATION);
+
+            let mut builder = ResourceFragmentBuilder::default();
+            builder
+                .position(
+                    transform.position() + speed.normalize_or_zero() * level.typical_radius() / 2.0,
+                )
+                .velocity(velocity.linvel + speed)
+                .amount(BOSS_REWARD_AMOUNT)
+                .radius(10.0);
+
+            commands.build_entity(&builder);
+        }
+
+        let shard_level = (level.level() - 2).max(1);
+        let angle_offset = rng.gen_range(-PI..PI);
+
+        for i in 0..BOSS_SHARD_COUNT {
+            let angle = angle_offset + 2.0 * PI * i as f32 / BOSS_SHARD_COUNT as f32;
+            let direction = Vec2::X.rotate_z(angle);
+
+            let mut builder = AsteroidBuilder::default();
+            builder
+                .position(transform.position() + direction * level.typical_radius() / 1.5)
+                .size_level(shard_level)
+                .base_velocity(velocity.linvel + direction * BOSS_SHARD_SPEED)
+                .base_angular_velocity(velocity.angvel);
+
+            commands.build_entity(&builder);
+        }
+
+        if let Some(kind) = PickupKind::reward_table().choose(&mut rng) {
+            let mut builder = PickupBuilder::default();
+            builder
+                .position(transform.position())
+                .velocity(velocity.linvel)
+                .kind(*kind)
+                .radius(20.0);
+
+            commands.build_entity(&builder);
+        }
+    }
+}
+
+/// Shows health and armour of the nearest boss in range
+fn boss_bars_update(
+    q_player: Query<&Transform, With<Player>>,
+    q_bosses: Query<(&Health, &MaxHealth, &Transform, Entity), Alive<BossAsteroid>>,
+    q_segments: Query<(&Health, &MaxHealth, &Parent), Alive<ArmorSegment>>,
+    mut q_hp_bar: Query<BossBarValues, (With<BossHP>, Without<BossArmor>)>,
+    mut q_armor_bar: Query<BossBarValues, (With<BossArmor>, Without<BossHP>)>,
+) {
+    let Ok(player_transform) = q_player.get_single() else {
+        return;
+    };
+    let player_position = player_transform.position();
+
+    let nearest = q_bosses
+        .iter()
+        .map(|(health, max_health, transform, entity)| {
+            let distance = transform.position().distance(player_position);
+            (health, max_health, distance, entity)
+        })
+        .filter(|(_, _, distance, _)| *distance <= BOSS_HUD_RANGE)
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+
+    let Some((health, max_health, _, boss)) = nearest else {
+        for (_, _, mut visibility) in q_hp_bar.iter_mut().chain(q_armor_bar.iter_mut()) {
+            *visibility = Visibility::Hidden;
+        }
+        return;
+    };
+
+    for (mut value, mut max_value, mut visibility) in q_hp_bar.iter_mut() {
+        *max_value = MaxValue(max_health.max_health());
+        *value = Value(health.health());
+        *visibility = Visibility::Inherited;
+    }
+
+    let (armor, max_armor) = q_segments
+        .iter()
+        .filter(|(_, _, parent)| parent.get() == boss)
+        .fold((0.0, 0.0), |(armor, max_armor), (health, max_health, _)| {
+            (armor + health.health(), max_armor + max_health.max_health())
+        });
+
+    for (mut value, mut max_value, mut visibility) in q_armor_bar.iter_mut() {
+        *max_value = MaxValue(max_armor);
+        *value = Value(armor);
+        *visibility = match 0.0 < armor {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        };
+    }
+}
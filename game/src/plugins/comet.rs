@@ -0,0 +1,194 @@
+use std::{f32::consts::PI, ops::Range, time::Duration};
+
+use bevy::prelude::*;
+use bevy_prototype_lyon::{prelude::*, shapes};
+use bevy_rapier2d::prelude::Velocity;
+use rand::Rng;
+
+use crate::{
+    components::{
+        camera::MainCamera,
+        comet::{Comet, CometBuilder, CometWarning},
+        common::{Despawn, DespawnOnExitGame, Layer, PositionBundle},
+        health::Dead,
+        player::Player,
+        ui::MainWindow,
+    },
+    entity::EntityBuildDirector,
+    goal::Goal,
+    math::{Position, RotateAroundZ},
+    stages::LivingStages,
+    states::GameState,
+};
+
+/// Comets appear this far from player, well out of screen
+const COMET_SPAWN_DISTANCE: f32 = 4000.0;
+const COMET_SPEED: Range<f32> = 500.0..800.0;
+/// How far comet path can pass by player
+const COMET_PATH_OFFSET: f32 = 600.0;
+
+/// Seconds between comets at the start of run
+const COMET_INTERVAL: Range<f32> = 20.0..40.0;
+/// Every reached goal shortens interval by this fraction
+const COMET_INTERVAL_GOAL_FACTOR: f32 = 0.1;
+
+/// Warning distance from screen edge
+const WARNING_EDGE_MARGIN: f32 = 30.0;
+/// Warning blinks with this period in seconds
+const WARNING_BLINK_PERIOD: f32 = 0.5;
+
+/// Counts time to next comet
+#[derive(Resource)]
+pub struct CometSpawner(Timer);
+
+impl CometSpawner {
+    fn restart(&mut self, goal_points: u32) {
+        let mut rng = rand::thread_rng();
+
+        let interval =
+            rng.gen_range(COMET_INTERVAL) / (1.0 + COMET_INTERVAL_GOAL_FACTOR * goal_points as f32);
+
+        self.0.set_duration(Duration::from_secs_f32(interval));
+        self.0.reset();
+    }
+}
+
+impl Default for CometSpawner {
+    fn default() -> Self {
+        Self(Timer::from_seconds(COMET_INTERVAL.end, TimerMode::Once))
+    }
+}
+
+pub struct CometPlugin;
+
+impl Plugin for CometPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CometSpawner>().add_systems((
+            comet_spawn.in_set(OnUpdate(GameState::InGame)),
+            comet_warning_update.in_set(OnUpdate(GameState::InGame)),
+            comet_dead.in_set(LivingStages::DeadProcessing),
+            comet_spawner_reset.in_schedule(OnExit(GameState::Respawn)),
+        ));
+    }
+}
+
+/// Launches comet across player position from random direction
+fn comet_spawn(
+    mut commands: Commands,
+    time: Res<Time>,
+    goal: Res<Goal>,
+    mut spawner: ResMut<CometSpawner>,
+    q_player: Query<&Transform, With<Player>>,
+) {
+    if !spawner.0.tick(time.delta()).finished() {
+        return;
+    }
+
+    spawner.restart(goal.points);
+
+    let Ok(player_transform) = q_player.get_single() else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+
+    let direction = Vec2::X.rotate_z(rng.gen_range(-PI..PI));
+    let offset = rng.gen_range(-COMET_PATH_OFFSET..COMET_PATH_OFFSET);
+    let position =
+        player_transform.position() - direction * COMET_SPAWN_DISTANCE + direction.perp() * offset;
+
+    let comet = commands.build_entity(
+        CometBuilder::default()
+            .position(position)
+            .velocity(direction * rng.gen_range(COMET_SPEED)),
+    );
+
+    spawn_warning(&mut commands, comet);
+}
+
+fn spawn_warning(commands: &mut Commands, comet: Entity) {
+    let arrow_radius = 15.0;
+    let arrow_angles: [f32; 3] = [0.0, -135.0, 135.0];
+
+    let points = arrow_angles
+        .iter()
+        .map(|angle| (Vec2::X * arrow_radius).rotate_z(angle.to_radians()))
+        .collect();
+
+    let shape = shapes::Polygon {
+        points,
+        closed: true,
+    };
+
+    commands
+        .spawn(CometWarning::new(comet))
+        .insert(ShapeBundle {
+            path: GeometryBuilder::build_as(&shape),
+            ..default()
+        })
+        .insert(Fill {
+            color: Color::RED,
+            options: default(),
+        })
+        .insert(PositionBundle::new(Vec2::ZERO, Layer::Effects))
+        .insert(DespawnOnExitGame);
+}
+
+/// Keeps warning on screen edge in direction of approaching comet
+fn comet_warning_update(
+    mut commands: Commands,
+    time: Res<Time>,
+    window: Query<&Window, With<MainWindow>>,
+    camera: Query<&Transform, (With<MainCamera>, Without<CometWarning>)>,
+    q_comets: Query<(&Transform, &Velocity), With<Comet>>,
+    mut q_warnings: Query<(&CometWarning, &mut Transform, &mut Visibility, Entity), Without<Comet>>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+    let Ok(camera) = camera.get_single() else {
+        return;
+    };
+
+    let half_size = Vec2::new(window.width(), window.height()) / 2.0 - WARNING_EDGE_MARGIN;
+    let blink_on = time.elapsed_seconds() % WARNING_BLINK_PERIOD < WARNING_BLINK_PERIOD / 2.0;
+
+    for (warning, mut transform, mut visibility, entity) in q_warnings.iter_mut() {
+        let Ok((comet_transform, comet_velocity)) = q_comets.get(warning.comet()) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+
+        let relative = comet_transform.position() - camera.position();
+
+        let on_screen = relative.x.abs() <= half_size.x && relative.y.abs() <= half_size.y;
+        let approaching = comet_velocity.linvel.dot(relative) < 0.0;
+
+        if on_screen || !approaching {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        // Scale relative position down until it touches screen edge
+        let scale = (half_size / relative.abs()).min_element();
+        let position = camera.position() + relative * scale;
+
+        *transform = Transform::from_translation(position.extend(Layer::Effects.into()))
+            .with_rotation(Quat::from_rotation_z(Vec2::X.angle_between(relative)));
+        *visibility = if blink_on {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn comet_dead(mut commands: Commands, q_comets: Query<Entity, (With<Dead>, With<Comet>)>) {
+    for entity in q_comets.iter() {
+        commands.entity(entity).insert(Despawn::Recursive);
+    }
+}
+
+fn comet_spawner_reset(mut spawner: ResMut<CometSpawner>) {
+    *spawner = CometSpawner::default();
+}
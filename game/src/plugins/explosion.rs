@@ -0,0 +1,143 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    components::{
+        common::Despawn,
+        faction::{Faction, FriendlyFire, ProjectileOwner},
+        health::{Dead, Health},
+        particle::explosion::ExplosionBurstBundle,
+        status::InflictStatus,
+    },
+    damage::{DamageEvent, DamageKind, StatusEvent},
+    explosion::{Explosion, Explosive},
+    math::Position,
+    stages::LivingStages,
+    states::GameState,
+};
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Explosion>()
+            .add_system(explosion_process.in_set(OnUpdate(GameState::InGame)))
+            .add_system(explode_dead.in_set(LivingStages::DeadProcessing));
+    }
+}
+
+/// Events explosions send to living entities in range
+#[derive(SystemParam)]
+struct ExplosionHits<'w> {
+    damage: EventWriter<'w, DamageEvent>,
+    status: EventWriter<'w, StatusEvent>,
+}
+
+fn explosion_process(
+    mut commands: Commands,
+    mut ev_explosions: EventReader<Explosion>,
+    rapier_context: Res<RapierContext>,
+    friendly_fire: Res<FriendlyFire>,
+    mut q_bodies: Query<(&Transform, Option<&mut ExternalImpulse>), With<RigidBody>>,
+    q_living: Query<Option<&Faction>, With<Health>>,
+    mut hits: ExplosionHits,
+) {
+    for explosion in ev_explosions.iter() {
+        let mut affected = Vec::new();
+
+        rapier_context.intersections_with_shape(
+            explosion.position,
+            0.0,
+            &Collider::ball(explosion.radius),
+            QueryFilter::default(),
+            |entity| {
+                affected.push(entity);
+                true
+            },
+        );
+
+        for entity in affected {
+            // Distance to nearest point of collider, zero if epicenter inside
+            let predicate = |candidate: Entity| candidate == entity;
+            let filter = QueryFilter::default().predicate(&predicate);
+            let nearest = rapier_context
+                .project_point(explosion.position, true, filter)
+                .map(|(_, projection)| projection.point);
+            let distance = nearest
+                .map(|point| point.distance(explosion.position))
+                .unwrap_or(explosion.radius);
+
+            let power = explosion.falloff(distance);
+            let mut direction = Vec2::ZERO;
+
+            if let Ok((transform, impulse)) = q_bodies.get_mut(entity) {
+                direction = (transform.position() - explosion.position).normalize_or_zero();
+                let new_impulse = direction * explosion.impulse * power;
+
+                match impulse {
+                    Some(mut impulse) => impulse.impulse += new_impulse,
+                    None => {
+                        commands.entity(entity).insert(ExternalImpulse {
+                            impulse: new_impulse,
+                            torque_impulse: 0.0,
+                        });
+                    }
+                }
+            }
+
+            let Ok(faction) = q_living.get(entity) else {
+                continue;
+            };
+
+            if !friendly_fire.allows(explosion.faction.as_ref(), faction) {
+                continue;
+            }
+
+            hits.damage.send(
+                DamageEvent::new(entity, explosion.damage * power, DamageKind::Explosion)
+                    .with_source(explosion.source)
+                    .with_hit(nearest.unwrap_or(explosion.position), direction),
+            );
+
+            if let Some(status) = explosion.status {
+                hits.status.send(StatusEvent::new(
+                    entity,
+                    status.with_source(explosion.source),
+                ));
+            }
+        }
+
+        commands.spawn(ExplosionBurstBundle::new(
+            explosion.position,
+            explosion.radius,
+        ));
+    }
+}
+
+/// Explosive with everything its explosion is credited to
+type ExplosiveSource = (
+    &'static Explosive,
+    &'static Transform,
+    Option<&'static ProjectileOwner>,
+    Option<&'static Faction>,
+    Option<&'static InflictStatus>,
+    Entity,
+);
+
+/// Explosion is credited to projectile shooter or to exploded entity itself.
+/// Fires once per death, dead player ship stays in world until respawn
+fn explode_dead(
+    q_explosives: Query<ExplosiveSource, (Added<Dead>, Without<Despawn>)>,
+    mut ev_explosions: EventWriter<Explosion>,
+) {
+    for (explosive, transform, owner, faction, status, entity) in q_explosives.iter() {
+        let source = owner.map_or(entity, |owner| owner.entity());
+
+        ev_explosions.send(
+            explosive
+                .explosion(transform.position())
+                .with_source(source, faction.copied())
+                .with_status(status.map(|status| status.effect())),
+        );
+    }
+}
@@ -5,7 +5,9 @@ use crate::{
     components::{
         common::{Layer, PositionBundle},
         player::Player,
+        repair::RepairZoneBuilder,
     },
+    entity::EntityBuildDirector,
     goal::{Goal, GoalChangeEvent, GOAL_REACH_RANGE, GOAL_UI_DISTANCE},
     math::{Position, RotateAroundZ},
     states::GameState,
@@ -44,13 +46,17 @@ fn goal_update_check(
     }
 }
 
+/// Reached goal leaves repair station behind
 fn goal_player_reached_check(
+    mut commands: Commands,
     mut goal: ResMut<Goal>,
     player_transform: Query<&Transform, With<Player>>,
 ) {
     let player_position = player_transform.single().position();
 
     if goal.position.distance(player_position) <= GOAL_REACH_RANGE {
+        commands.build_entity(RepairZoneBuilder::default().position(goal.position));
+
         goal.player_reached();
     }
 }
@@ -0,0 +1,176 @@
+use bevy::prelude::*;
+
+use crate::{
+    goal::Goal,
+    highscore::{HighScore, HighScores, PendingHighScore, HIGH_SCORE_NAME_LEN},
+    random::RunSeed,
+    score::Score,
+    states::GameState,
+};
+
+/// Name used when player skips name entry
+const DEFAULT_NAME: &str = "Pilot";
+
+/// Mark for high-score table text on respawn screen
+#[derive(Component, Clone)]
+pub struct HighScoreTable;
+
+pub struct HighScorePlugin;
+
+impl Plugin for HighScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HighScores::load())
+            .init_resource::<RunSeed>()
+            .add_system(highscore_check.in_schedule(OnEnter(GameState::Respawn)))
+            .add_systems(
+                (highscore_name_entry, highscore_table_update)
+                    .chain()
+                    .in_set(OnUpdate(GameState::Respawn)),
+            )
+            .add_systems(
+                (highscore_submit, run_seed_reset).in_schedule(OnExit(GameState::Respawn)),
+            );
+    }
+}
+
+/// Starts name entry if finished run gets into table
+fn highscore_check(
+    mut commands: Commands,
+    high_scores: Res<HighScores>,
+    score: Res<Score>,
+    goal: Res<Goal>,
+    seed: Res<RunSeed>,
+) {
+    if !high_scores.qualifies(score.points()) {
+        return;
+    }
+
+    commands.insert_resource(PendingHighScore(HighScore::new(
+        score.points(),
+        goal.points,
+        score.time(),
+        seed.seed(),
+    )));
+}
+
+fn highscore_name_entry(
+    mut ev_chars: EventReader<ReceivedCharacter>,
+    keys: Res<Input<KeyCode>>,
+    pending: Option<ResMut<PendingHighScore>>,
+    mut high_scores: ResMut<HighScores>,
+    mut commands: Commands,
+) {
+    let Some(mut pending) = pending else {
+        ev_chars.clear();
+        return;
+    };
+
+    for ev in ev_chars.iter() {
+        let name = &mut pending.0.name;
+        if (ev.char.is_alphanumeric() || ev.char == ' ')
+            && name.chars().count() < HIGH_SCORE_NAME_LEN
+        {
+            name.push(ev.char);
+        }
+    }
+
+    if keys.just_pressed(KeyCode::Back) {
+        pending.0.name.pop();
+    }
+
+    if keys.just_pressed(KeyCode::Return) {
+        save_high_score(&mut high_scores, pending.0.clone());
+        commands.remove_resource::<PendingHighScore>();
+    }
+}
+
+/// Saves record if player left respawn screen before pressing Enter
+fn highscore_submit(
+    pending: Option<Res<PendingHighScore>>,
+    mut high_scores: ResMut<HighScores>,
+    mut commands: Commands,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+
+    save_high_score(&mut high_scores, pending.0.clone());
+    commands.remove_resource::<PendingHighScore>();
+}
+
+fn save_high_score(high_scores: &mut HighScores, mut score: HighScore) {
+    score.name = score.name.trim().to_string();
+    if score.name.is_empty() {
+        score.name = DEFAULT_NAME.to_string();
+    }
+
+    high_scores.insert(score);
+
+    if let Err(err) = high_scores.save() {
+        warn!("Failed to save high scores: {}", err);
+    }
+}
+
+fn highscore_table_update(
+    mut q_tables: Query<&mut Text, With<HighScoreTable>>,
+    high_scores: Res<HighScores>,
+    pending: Option<Res<PendingHighScore>>,
+) {
+    // Pending record is shown in its place with cursor after name
+    let mut table = high_scores.clone();
+    let pending_place = pending.as_ref().and_then(|pending| {
+        let mut preview = pending.0.clone();
+        preview.name.push('_');
+        table.insert(preview)
+    });
+
+    let mut content = match pending_place {
+        Some(_) => "NEW RECORD! Type your name and press Enter\n\n".to_string(),
+        None => "HIGH SCORES\n\n".to_string(),
+    };
+
+    content += &format!(
+        "   {:<2} {:<width$} {:>8} {:>5} {:>6} {:>10}\n",
+        "#",
+        "NAME",
+        "POINTS",
+        "GOALS",
+        "TIME",
+        "DATE",
+        width = HIGH_SCORE_NAME_LEN + 1
+    );
+
+    for (place, score) in table.iter().enumerate() {
+        let mark = if pending_place == Some(place) {
+            ">"
+        } else {
+            " "
+        };
+
+        content += &format!(
+            "{} {:>3} {:<width$} {:>8} {:>5} {:>6} {:>10}\n",
+            mark,
+            place + 1,
+            score.name,
+            score.points,
+            score.goals,
+            score.duration_string(),
+            score.date_string(),
+            width = HIGH_SCORE_NAME_LEN + 1
+        );
+    }
+
+    for mut text in q_tables.iter_mut() {
+        let Some(section) = text.sections.first_mut() else {
+            continue;
+        };
+
+        if section.value != content {
+            section.value = content.clone();
+        }
+    }
+}
+
+fn run_seed_reset(mut seed: ResMut<RunSeed>) {
+    seed.reseed();
+}
@@ -2,20 +2,57 @@ use bevy::prelude::*;
 
 use crate::{
     components::{
+        cargo::Cargo,
         health::{Health, MaxHealth},
+        pickup::PowerUps,
         player::Player,
+        shield::Shield,
+        status::StatusEffects,
         ui::{button::ButtonColorsConfig, progressbar::*},
+        weapon::{Magazine, WeaponGroup, WeaponHeat, WeaponLoadout, WeaponName, WeaponSlot},
     },
     entity::{ComponentInjectorBuilder, EntityBuildDirector, EntityBuilder},
+    score::Score,
     stages::UiUpdate,
 };
 
+const HUD_FONT: &str = "fonts/FiraMono-Medium.ttf";
+
 #[derive(Component)]
 pub struct Root;
 
 #[derive(Component, Clone)]
 pub struct PlayerHP;
 
+#[derive(Component, Clone)]
+pub struct PlayerHeat;
+
+#[derive(Component, Clone)]
+pub struct PlayerAmmo;
+
+#[derive(Component, Clone)]
+pub struct PlayerShield;
+
+/// Text with selected weapons of player
+#[derive(Component)]
+pub struct PlayerWeapons;
+
+/// Text with active status effects of player
+#[derive(Component)]
+pub struct PlayerStatus;
+
+/// Text with active power-ups of player
+#[derive(Component)]
+pub struct PlayerPowerUps;
+
+/// Text with loaded cargo of player
+#[derive(Component)]
+pub struct PlayerCargo;
+
+/// Text with score and combo multiplier
+#[derive(Component)]
+pub struct ScoreText;
+
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
@@ -24,12 +61,20 @@ impl Plugin for HudPlugin {
             .configure_set(UiUpdate.after(CoreSet::PostUpdate))
             .add_system(progress_bar_update.in_base_set(UiUpdate))
             .add_system(button_effects.in_base_set(UiUpdate))
-            .add_system(update_player_hp);
+            .add_system(update_player_hp)
+            .add_system(update_player_heat)
+            .add_system(update_player_ammo)
+            .add_system(update_player_shield)
+            .add_system(update_player_weapons)
+            .add_system(update_player_status)
+            .add_system(update_player_power_ups)
+            .add_system(update_player_cargo)
+            .add_system(update_score);
     }
 }
 
 impl HudPlugin {
-    fn create_player_hp_builder() -> impl EntityBuilder {
+    fn create_player_bar_builder(left: Val, color: Color) -> ProgressBarBuilder {
         let mut progress_bar_builder = ProgressBarBuilder::default();
         progress_bar_builder
             .min(0.0)
@@ -38,17 +83,59 @@ impl HudPlugin {
                 size: Size::new(Val::Percent(10.0), Val::Percent(2.5)),
                 position: UiRect {
                     bottom: Val::Px(5.0),
-                    left: Val::Px(5.0),
+                    left,
                     ..Default::default()
                 },
                 position_type: PositionType::Absolute,
                 ..Default::default()
             })
-            .color_front(Color::RED)
+            .color_front(color)
             .color_back(Color::WHITE);
 
+        progress_bar_builder
+    }
+
+    fn create_player_text(font: Handle<Font>, bottom: Val) -> TextBundle {
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font,
+                font_size: 20.0,
+                color: Color::WHITE,
+            },
+        )
+        .with_style(Style {
+            position: UiRect {
+                bottom,
+                left: Val::Px(5.0),
+                ..Default::default()
+            },
+            position_type: PositionType::Absolute,
+            ..Default::default()
+        })
+    }
+
+    fn create_player_hp_builder() -> impl EntityBuilder {
+        let progress_bar_builder = Self::create_player_bar_builder(Val::Px(5.0), Color::RED);
         ComponentInjectorBuilder::new(progress_bar_builder, PlayerHP)
     }
+
+    fn create_player_heat_builder() -> impl EntityBuilder {
+        let progress_bar_builder =
+            Self::create_player_bar_builder(Val::Percent(11.0), Color::ORANGE);
+        ComponentInjectorBuilder::new(progress_bar_builder, PlayerHeat)
+    }
+
+    fn create_player_ammo_builder() -> impl EntityBuilder {
+        let progress_bar_builder =
+            Self::create_player_bar_builder(Val::Percent(22.0), Color::YELLOW);
+        ComponentInjectorBuilder::new(progress_bar_builder, PlayerAmmo)
+    }
+
+    fn create_player_shield_builder() -> impl EntityBuilder {
+        let progress_bar_builder = Self::create_player_bar_builder(Val::Percent(33.0), Color::CYAN);
+        ComponentInjectorBuilder::new(progress_bar_builder, PlayerShield)
+    }
 }
 
 fn progress_bar_update(
@@ -82,7 +169,7 @@ fn progress_bar_update(
     }
 }
 
-fn create_root_element(mut commands: Commands) {
+fn create_root_element(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands
         .spawn(NodeBundle {
             style: Style {
@@ -97,11 +184,70 @@ fn create_root_element(mut commands: Commands) {
         .with_children(|cs| {
             let hp_builder = HudPlugin::create_player_hp_builder();
             cs.build_entity(&hp_builder);
+
+            let heat_builder = HudPlugin::create_player_heat_builder();
+            cs.build_entity(&heat_builder);
+
+            let ammo_builder = HudPlugin::create_player_ammo_builder();
+            cs.build_entity(&ammo_builder);
+
+            let shield_builder = HudPlugin::create_player_shield_builder();
+            cs.build_entity(&shield_builder);
+
+            let font = asset_server.load(HUD_FONT);
+
+            cs.spawn(HudPlugin::create_player_text(
+                font.clone(),
+                Val::Percent(3.5),
+            ))
+            .insert(PlayerWeapons);
+
+            cs.spawn(HudPlugin::create_player_text(
+                font.clone(),
+                Val::Percent(7.0),
+            ))
+            .insert(PlayerStatus);
+
+            cs.spawn(HudPlugin::create_player_text(
+                font.clone(),
+                Val::Percent(10.5),
+            ))
+            .insert(PlayerCargo);
+
+            cs.spawn(HudPlugin::create_player_text(
+                font.clone(),
+                Val::Percent(14.0),
+            ))
+            .insert(PlayerPowerUps);
+
+            cs.spawn(
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font,
+                        font_size: 32.0,
+                        color: Color::GOLD,
+                    },
+                )
+                .with_style(Style {
+                    position: UiRect {
+                        top: Val::Px(5.0),
+                        right: Val::Px(10.0),
+                        ..Default::default()
+                    },
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                }),
+            )
+            .insert(ScoreText);
         });
 }
 
+/// Value and max value of player progress bar
+type PlayerBarValues = (&'static mut Value, &'static mut MaxValue);
+
 fn update_player_hp(
-    mut q_progress_bars: Query<(&mut Value, &mut MaxValue), (With<ProgressBar>, Without<Player>)>,
+    mut q_progress_bars: Query<PlayerBarValues, (With<PlayerHP>, Without<Player>)>,
     q_player: Query<(&Health, &MaxHealth), With<Player>>,
 ) {
     let (player_hp, player_max_hp) = q_player.single();
@@ -112,6 +258,190 @@ fn update_player_hp(
     }
 }
 
+fn update_player_shield(
+    mut q_progress_bars: Query<PlayerBarValues, With<PlayerShield>>,
+    q_player: Query<&Shield, With<Player>>,
+) {
+    let Ok(shield) = q_player.get_single() else {
+        return;
+    };
+
+    for (mut value, mut max_value) in q_progress_bars.iter_mut() {
+        *max_value = MaxValue(shield.capacity());
+        *value = Value(shield.charge());
+    }
+}
+
+fn update_player_heat(
+    mut q_progress_bars: Query<PlayerBarValues, With<PlayerHeat>>,
+    q_player: Query<(&Children, &WeaponLoadout), With<Player>>,
+    q_heat: Query<(&WeaponHeat, &WeaponGroup, &WeaponSlot)>,
+) {
+    let (children, loadout) = q_player.single();
+
+    // Heat of first selected weapon
+    let heat = children
+        .iter()
+        .filter_map(|e| q_heat.get(*e).ok())
+        .find(|(_, group, slot)| loadout.is_active(**group, **slot))
+        .map(|(heat, _, _)| heat.heat())
+        .unwrap_or_default();
+
+    for (mut value, mut max_value) in q_progress_bars.iter_mut() {
+        *max_value = MaxValue(1.0);
+        *value = Value(heat);
+    }
+}
+
+fn update_player_ammo(
+    mut q_progress_bars: Query<PlayerBarValues, With<PlayerAmmo>>,
+    q_player: Query<(&Children, &WeaponLoadout), With<Player>>,
+    q_magazines: Query<(&Magazine, &WeaponGroup, &WeaponSlot)>,
+) {
+    let (children, loadout) = q_player.single();
+
+    // Magazine of first selected weapon
+    let magazine = children
+        .iter()
+        .filter_map(|e| q_magazines.get(*e).ok())
+        .find(|(_, group, slot)| loadout.is_active(**group, **slot))
+        .map(|(magazine, _, _)| magazine);
+
+    let Some(magazine) = magazine else {
+        return;
+    };
+
+    let capacity = magazine.capacity() as f32;
+
+    // Show reload progress while magazine is reloading
+    let rounds = match magazine.reloading() {
+        true => magazine.reload_progress() * capacity,
+        false => magazine.rounds() as f32,
+    };
+
+    for (mut value, mut max_value) in q_progress_bars.iter_mut() {
+        *max_value = MaxValue(capacity);
+        *value = Value(rounds);
+    }
+}
+
+fn update_player_weapons(
+    mut q_text: Query<&mut Text, With<PlayerWeapons>>,
+    q_player: Query<(&Children, Ref<WeaponLoadout>), With<Player>>,
+    q_weapons: Query<(&WeaponName, &WeaponGroup, &WeaponSlot)>,
+) {
+    let Ok((children, loadout)) = q_player.get_single() else {
+        return;
+    };
+
+    if !loadout.is_changed() {
+        return;
+    }
+
+    let selected_name = |selected_group: WeaponGroup| {
+        children
+            .iter()
+            .filter_map(|e| q_weapons.get(*e).ok())
+            .find(|(_, group, slot)| {
+                **group == selected_group && loadout.is_active(**group, **slot)
+            })
+            .map(|(name, _, _)| name.name())
+            .unwrap_or("-")
+    };
+
+    let text_value = format!(
+        "{} [{}] | {}",
+        selected_name(WeaponGroup::Primary),
+        loadout.fire_mode().name(),
+        selected_name(WeaponGroup::Secondary),
+    );
+
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = text_value.clone();
+    }
+}
+
+/// Status durations tick without change detection, so text is refreshed every frame
+fn update_player_status(
+    mut q_text: Query<&mut Text, With<PlayerStatus>>,
+    q_player: Query<&StatusEffects, With<Player>>,
+) {
+    let Ok(effects) = q_player.get_single() else {
+        return;
+    };
+
+    let text_value = effects
+        .iter()
+        .map(|effect| match effect.stacks() {
+            1 => format!("{} ({:.1}s)", effect.kind().name(), effect.duration()),
+            stacks => format!(
+                "{} x{} ({:.1}s)",
+                effect.kind().name(),
+                stacks,
+                effect.duration()
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("  ");
+
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = text_value.clone();
+    }
+}
+
+/// Power-up timers tick without change detection, so text is refreshed every frame
+fn update_player_power_ups(
+    mut q_text: Query<&mut Text, With<PlayerPowerUps>>,
+    q_player: Query<&PowerUps, With<Player>>,
+) {
+    let Ok(power_ups) = q_player.get_single() else {
+        return;
+    };
+
+    let text_value = power_ups
+        .iter()
+        .map(|power_up| format!("{} ({:.1}s)", power_up.kind().name(), power_up.duration()))
+        .collect::<Vec<_>>()
+        .join("  ");
+
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = text_value.clone();
+    }
+}
+
+fn update_player_cargo(
+    mut q_text: Query<&mut Text, With<PlayerCargo>>,
+    q_player: Query<&Cargo, (With<Player>, Changed<Cargo>)>,
+) {
+    let Ok(cargo) = q_player.get_single() else {
+        return;
+    };
+
+    let text_value = match cargo.is_full() {
+        true => format!("Cargo {}/{} FULL", cargo.amount(), cargo.capacity()),
+        false => format!("Cargo {}/{}", cargo.amount(), cargo.capacity()),
+    };
+
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = text_value.clone();
+    }
+}
+
+fn update_score(mut q_text: Query<&mut Text, With<ScoreText>>, score: Res<Score>) {
+    if !score.is_changed() {
+        return;
+    }
+
+    let text_value = match score.multiplier() {
+        multiplier if 1.0 < multiplier => format!("{}  x{:.2}", score.points(), multiplier),
+        _ => format!("{}", score.points()),
+    };
+
+    for mut text in q_text.iter_mut() {
+        text.sections[0].value = text_value.clone();
+    }
+}
+
 fn button_effects(
     mut button_query: Query<
         (&Interaction, &mut BackgroundColor, &ButtonColorsConfig),
@@ -4,11 +4,17 @@ use crate::{
     components::{
         common::Despawn,
         health::{
-            Dead, Health, Immortality, MaxHealth, RegenerateOneTimeToFull, TimedImmortality,
-            TimedImmortalityBundle,
+            Dead, FatalHit, Health, HealthRegeneration, Immortality, MaxHealth,
+            RegenerateOneTimeToFull, Resistances, TimedImmortality, TimedImmortalityBundle,
         },
+        repair::RepairZone,
+        shield::Shield,
+        ship::Ship,
     },
+    damage::{AppliedDamageEvent, DamageEvent, DeathEvent, HealEvent},
+    math::Position,
     stages::LivingStages,
+    states::GameState,
 };
 
 #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
@@ -17,6 +23,9 @@ pub struct DeadMarkInserterSystemSet;
 #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
 pub struct RegenerateSystemSet;
 
+#[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone)]
+pub struct DamageSystemSet;
+
 pub struct LivingPlugin;
 
 impl Plugin for LivingPlugin {
@@ -30,7 +39,19 @@ impl Plugin for LivingPlugin {
                 .chain(),
         );
 
-        app.configure_sets((RegenerateSystemSet, DeadMarkInserterSystemSet).chain());
+        app.configure_sets(
+            (
+                RegenerateSystemSet,
+                DamageSystemSet,
+                DeadMarkInserterSystemSet,
+            )
+                .chain(),
+        );
+
+        app.add_event::<DamageEvent>()
+            .add_event::<AppliedDamageEvent>()
+            .add_event::<HealEvent>()
+            .add_event::<DeathEvent>();
 
         app.add_system(
             regenerate_one_time
@@ -38,6 +59,19 @@ impl Plugin for LivingPlugin {
                 .in_set(RegenerateSystemSet),
         );
 
+        app.add_systems(
+            (damage_apply, heal_apply)
+                .chain()
+                .in_set(LivingStages::HealthProcessing)
+                .in_set(DamageSystemSet),
+        );
+
+        app.add_systems(
+            (health_regeneration, repair_zones)
+                .in_set(OnUpdate(GameState::InGame))
+                .before(DamageSystemSet),
+        );
+
         app.add_systems(
             (dead_mark_inserter, timed_immortality_update)
                 .in_set(LivingStages::HealthProcessing)
@@ -59,6 +93,117 @@ fn regenerate_one_time(
     }
 }
 
+/// Entities damage can be applied to
+type Vulnerable = (Without<Immortality>, Without<Dead>);
+
+/// Applies damage requests with immunity and resistances.
+/// Sends [AppliedDamageEvent] with health lost and [DeathEvent] on lethal damage
+fn damage_apply(
+    mut commands: Commands,
+    mut ev_damage: EventReader<DamageEvent>,
+    mut ev_applied: EventWriter<AppliedDamageEvent>,
+    mut ev_death: EventWriter<DeathEvent>,
+    mut q_living: Query<(&mut Health, Option<&Resistances>, Option<&mut Shield>), Vulnerable>,
+) {
+    for damage in ev_damage.iter() {
+        let Ok((mut health, resistances, shield)) = q_living.get_mut(damage.target) else {
+            continue;
+        };
+
+        if !health.alive() {
+            continue;
+        }
+
+        let amount = match resistances {
+            Some(resistances) => resistances.reduce(damage.kind, damage.amount),
+            None => damage.amount,
+        };
+
+        // Shield takes damage before hull
+        let amount = match shield {
+            Some(mut shield) => shield.absorb(amount),
+            None => amount,
+        };
+
+        if amount <= 0.0 {
+            continue;
+        }
+
+        let health_before = health.health();
+        health.damage(amount);
+
+        ev_applied.send(AppliedDamageEvent {
+            target: damage.target,
+            amount: health_before - health.health(),
+            source: damage.source,
+            kind: damage.kind,
+        });
+
+        if !health.alive() {
+            if let Some(hit) = damage.hit {
+                commands.entity(damage.target).insert(FatalHit::new(hit));
+            }
+
+            ev_death.send(DeathEvent {
+                entity: damage.target,
+                source: damage.source,
+                cause: damage.kind,
+            });
+        }
+    }
+}
+
+fn heal_apply(
+    mut ev_heal: EventReader<HealEvent>,
+    mut q_living: Query<(&mut Health, &MaxHealth), Without<Dead>>,
+) {
+    for heal in ev_heal.iter() {
+        let Ok((mut health, max_health)) = q_living.get_mut(heal.target) else {
+            continue;
+        };
+
+        health.heal(heal.amount, max_health.max_health());
+    }
+}
+
+fn health_regeneration(
+    mut q_living: Query<(&mut HealthRegeneration, &Health, &MaxHealth, Entity), Without<Dead>>,
+    mut ev_heal: EventWriter<HealEvent>,
+    time: Res<Time>,
+) {
+    for (mut regeneration, health, max_health, entity) in q_living.iter_mut() {
+        let amount = regeneration.update(
+            time.delta_seconds(),
+            health.health(),
+            max_health.max_health(),
+        );
+
+        if 0.0 < amount {
+            ev_heal.send(HealEvent::new(entity, amount));
+        }
+    }
+}
+
+/// Heals ships inside zones. Dead ships are skipped by [heal_apply]
+fn repair_zones(
+    q_zones: Query<(&RepairZone, &Transform)>,
+    q_living: Query<(&Transform, Entity), With<Ship>>,
+    mut ev_heal: EventWriter<HealEvent>,
+    time: Res<Time>,
+) {
+    for (zone, zone_transform) in q_zones.iter() {
+        let zone_position = zone_transform.position();
+
+        for (transform, entity) in q_living.iter() {
+            if zone.radius() < transform.position().distance(zone_position) {
+                continue;
+            }
+
+            ev_heal.send(HealEvent::new(entity, zone.rate() * time.delta_seconds()));
+        }
+    }
+}
+
 fn dead_mark_inserter(
     mut commands: Commands,
     q_entities: Query<(&Health, Entity), (Changed<Health>, Without<Dead>, Without<Despawn>)>,
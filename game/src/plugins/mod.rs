@@ -1,14 +1,24 @@
+/// Adds achievements: unlock checks, toasts and achievements page opened from menus
+pub mod achievement;
 /// Adds astroid spawning/despawning in world
 pub mod asteroid;
 /// Adds background to game
 pub mod background;
+/// Adds boss asteroids: spawning on goal thresholds, armour, shedding and health bar
+pub mod boss;
+/// Adds comets: hazards crossing the field with screen edge warnings
+pub mod comet;
 /// Adds despawn options for objects by using [DespawnOn](crate::components::common::DespawnOn) mark
 pub mod despawn;
+/// Adds explosions: pushes and damages objects around, explodes dead [Explosive](crate::explosion::Explosive) entities
+pub mod explosion;
+/// Adds local high-score table with name entry on respawn screen
+pub mod highscore;
 /// Adds hud to screen
 pub mod hud;
 /// Handles most of InGame state logic(Despawn, Reset, etc)
 pub mod ingame;
-/// Adds living(entity with [Health]) processing: adds [Dead] marks so you can process what to do when object is dead
+/// Adds living(entity with [Health]) processing: applies damage and heal events, adds [Dead] marks so you can process what to do when object is dead
 pub mod living;
 /// Adds particles processing
 pub mod particle;
@@ -16,17 +26,30 @@ pub mod particle;
 pub mod pause;
 /// Adds physic to world: no gravity enviroments and ability to sum external forces of children
 pub mod physics;
+/// Adds pickups: drops collection, timed power-ups and blinking before despawn
+pub mod pickup;
 /// Adds player ship and controls for it
 pub mod player;
+/// Adds collection of [ResourceFragment](crate::components::resource::ResourceFragment) into player [Cargo](crate::components::cargo::Cargo) and its delivery to goals
+pub mod resource;
 /// Adds respawn menu
 pub mod respawn;
+/// Adds shield regeneration and shield bubble effects
+pub mod shield;
 /// Adds ship processing system
 pub mod ship;
+/// Adds run statistics collection: distance, kills, accuracy, damage
+pub mod stats;
+/// Adds status effects: burning, EMP, slowed, corroded
+pub mod status;
 /// Adds weapons processing system
 pub mod weapon;
 
 /// Adds goal to the game and score system
 pub mod goal;
 
+/// Adds points for destroyed asteroids and reached goals with "+N" popups
+pub mod score;
+
 /// Adds displayable information on entities with its health
 pub mod healthdisplay;
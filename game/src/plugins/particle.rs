@@ -8,8 +8,8 @@ use crate::{
     components::{
         common::{Active, Layer, MaxTimeToLive, Reset, TimeToLive},
         particle::{
-            ParticleColor, ParticleGenerator, ParticleGeneratorDeviation, ParticleGeneratorRate,
-            ParticleSize, ParticleVelocity,
+            ParticleBundle, ParticleBurst, ParticleColor, ParticleGenerator,
+            ParticleGeneratorDeviation, ParticleGeneratorRate, ParticleSize, ParticleVelocity,
         },
     },
     math::{Angle, RotateAroundZ},
@@ -34,7 +34,10 @@ pub struct ParticlePlugin;
 impl Plugin for ParticlePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ParticleMesh>()
-            .add_system(particle_generator_hierarchical_spawn.in_set(OnUpdate(GameState::InGame)))
+            .add_systems(
+                (particle_generator_hierarchical_spawn, particle_burst_spawn)
+                    .in_set(OnUpdate(GameState::InGame)),
+            )
             .add_systems(
                 (
                     particle_color_update,
@@ -79,35 +82,88 @@ fn particle_generator_hierarchical_spawn(
             end_velocity + parent_velocity.linvel,
         );
 
-        // Prepare base position and scale
         let particle_position = transform.translation().truncate();
-        let scale = Vec2::splat(particle_bundle.size.start()).extend(1.0);
-
-        let material = materials.add(ColorMaterial::from(particle_bundle.color.start()));
-
-        // Generate particles
         let count = rate.particles_count(&mut rng);
 
-        for _ in 0..count {
-            // Deviate position
-            let current_particle_position = particle_position.deviate(&mut rng, deviation.get());
+        spawn_particles(
+            &mut commands,
+            &particle_bundle,
+            count,
+            particle_position,
+            deviation.get(),
+            &particle_mesh,
+            &mut materials,
+        );
+    }
+}
 
-            let mesh = MaterialMesh2dBundle {
-                mesh: particle_mesh.0.clone().into(),
-                transform: Transform::from_translation(
-                    current_particle_position.extend(Layer::Effects.into()),
-                )
-                .with_scale(scale),
-                material: material.clone(),
-                ..default()
-            };
-
-            commands
-                .spawn(mesh)
-                .insert(RigidBody::Dynamic)
-                .insert(Velocity::linear(particle_bundle.velocity.start()))
-                .insert(particle_bundle.clone());
+/// For one shot generators. Every particle of burst is generated separately
+fn particle_burst_spawn(
+    mut commands: Commands,
+    q_generators: Query<(
+        &ParticleGenerator,
+        &ParticleBurst,
+        &ParticleGeneratorDeviation,
+        &Transform,
+        Entity,
+    )>,
+    particle_mesh: Res<ParticleMesh>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for (generator, burst, deviation, transform, entity) in q_generators.iter() {
+        let particle_position = transform.translation.truncate();
+
+        for _ in 0..burst.count() {
+            spawn_particles(
+                &mut commands,
+                &generator.particle(),
+                1,
+                particle_position,
+                deviation.get(),
+                &particle_mesh,
+                &mut materials,
+            );
         }
+
+        commands.entity(entity).despawn();
+    }
+}
+
+fn spawn_particles(
+    commands: &mut Commands,
+    particle_bundle: &ParticleBundle,
+    count: usize,
+    position: Vec2,
+    deviation: f64,
+    particle_mesh: &ParticleMesh,
+    materials: &mut Assets<ColorMaterial>,
+) {
+    let mut rng = rand::thread_rng();
+
+    // Prepare base scale
+    let scale = Vec2::splat(particle_bundle.size.start()).extend(1.0);
+
+    let material = materials.add(ColorMaterial::from(particle_bundle.color.start()));
+
+    for _ in 0..count {
+        // Deviate position
+        let current_particle_position = position.deviate(&mut rng, deviation);
+
+        let mesh = MaterialMesh2dBundle {
+            mesh: particle_mesh.0.clone().into(),
+            transform: Transform::from_translation(
+                current_particle_position.extend(Layer::Effects.into()),
+            )
+            .with_scale(scale),
+            material: material.clone(),
+            ..default()
+        };
+
+        commands
+            .spawn(mesh)
+            .insert(RigidBody::Dynamic)
+            .insert(Velocity::linear(particle_bundle.velocity.start()))
+            .insert(particle_bundle.clone());
     }
 }
 
@@ -2,6 +2,7 @@ use bevy::{app::AppExit, prelude::*};
 
 use crate::components::ui::button::ButtonBuilder;
 use crate::entity::{ComponentInjectorBuilder, EntityChildBuildDirector};
+use crate::plugins::achievement::AchievementsButton;
 use crate::states::GameState;
 
 const MENU_FONT: &str = "fonts/FiraMono-Medium.ttf";
@@ -60,6 +61,15 @@ fn build_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
     let continue_button_builder =
         ComponentInjectorBuilder::new(continue_button_builder, ContinueButton);
 
+    let mut achievements_button_builder = ButtonBuilder::default();
+    achievements_button_builder
+        .text("Achievements".to_string())
+        .font(font.clone())
+        .style(button_style());
+
+    let achievements_button_builder =
+        ComponentInjectorBuilder::new(achievements_button_builder, AchievementsButton);
+
     let mut exit_button_builder = ButtonBuilder::default();
     exit_button_builder
         .text("Exit".to_string())
@@ -91,6 +101,7 @@ fn build_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
             ..Default::default()
         })
         .build_child_entity(&continue_button_builder)
+        .build_child_entity(&achievements_button_builder)
         .build_child_entity(&exit_button_builder);
 }
 
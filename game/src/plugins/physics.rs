@@ -2,9 +2,17 @@ use std::collections::HashMap;
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
 
 use crate::{
-    components::health::{CollisionDamage, Health, Immortality, TimedImmortalityBundle},
+    components::{
+        faction::{Faction, FriendlyFire, ProjectileOwner},
+        health::{
+            Armor, CollisionDamage, DamageThreshold, PreStepVelocity, TimedImmortalityBundle,
+        },
+    },
+    damage::{collision_damage, impact_energy, reduced_mass, DamageEvent, DamageKind},
+    math::Position,
     states::GameState,
 };
 
@@ -16,6 +24,7 @@ impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0))
             // .add_plugin(RapierDebugRenderPlugin::default())
+            .init_resource::<FriendlyFire>()
             .add_startup_system(no_gravity);
 
         // Update forces
@@ -25,8 +34,12 @@ impl Plugin for PhysicsPlugin {
                 .pipe(external_forces_apply)
                 .in_set(OnUpdate(GameState::InGame)),
         );
-        // Damage
-        app.add_system(damage_collided_entities.in_set(OnUpdate(GameState::InGame)));
+        // Damage. Velocities stored after damage is processed are used in next physics step
+        app.add_systems(
+            (damage_collided_entities, store_pre_step_velocity)
+                .chain()
+                .in_set(OnUpdate(GameState::InGame)),
+        );
 
         // Pausing
         app.add_systems((
@@ -103,35 +116,146 @@ fn external_forces_apply(
 
 fn damage_collided_entities(
     mut commands: Commands,
-    mut collision_events: EventReader<ContactForceEvent>,
-    mut q_entities: Query<(&mut Health, Entity), (With<CollisionDamage>, Without<Immortality>)>,
+    mut collision_events: EventReader<CollisionEvent>,
+    rapier_context: Res<RapierContext>,
+    friendly_fire: Res<FriendlyFire>,
+    q_sources: Query<(Option<&Faction>, Option<&ProjectileOwner>)>,
+    q_entities: Query<
+        (
+            &ReadMassProperties,
+            &PreStepVelocity,
+            &Armor,
+            &DamageThreshold,
+            &Transform,
+        ),
+        With<CollisionDamage>,
+    >,
+    mut ev_damage: EventWriter<DamageEvent>,
 ) {
     for e in collision_events.iter() {
-        let first = e.collider1;
-        let second = e.collider2;
+        let CollisionEvent::Started(first_entity, second_entity, flags) = *e else {
+            continue;
+        };
+
+        if flags.contains(CollisionEventFlags::SENSOR) {
+            continue;
+        }
+
+        let Ok([first, second]) = q_entities.get_many([first_entity, second_entity]) else {
+            continue;
+        };
+
+        let (first_mass, first_velocity, first_armor, first_threshold, first_transform) = first;
+        let (second_mass, second_velocity, second_armor, second_threshold, second_transform) =
+            second;
+
+        let relative_velocity = first_velocity.velocity() - second_velocity.velocity();
+
+        // Only velocity along contact normal is lost in impact
+        let normal = rapier_context
+            .contact_pair(first_entity, second_entity)
+            .and_then(|pair| pair.manifolds().next().map(|manifold| manifold.normal()))
+            .unwrap_or_else(|| relative_velocity.normalize_or_zero());
+
+        let mass = reduced_mass(first_mass.0.mass, second_mass.0.mass);
+        let energy = impact_energy(relative_velocity, normal, mass);
+
+        let first_damage =
+            collision_damage(energy, first_threshold.threshold(), first_armor.armor());
+        let second_damage =
+            collision_damage(energy, second_threshold.threshold(), second_armor.armor());
+
+        let (first_faction, first_owner) = q_sources.get(first_entity).unwrap_or_default();
+        let (second_faction, second_owner) = q_sources.get(second_entity).unwrap_or_default();
 
-        let Ok(entities) = q_entities.get_many_mut([first, second]) else {
+        let first_damage_source = damage_source(
+            &friendly_fire,
+            first_entity,
+            first_faction,
+            second_entity,
+            second_faction,
+            second_owner,
+        );
+        let second_damage_source = damage_source(
+            &friendly_fire,
+            second_entity,
+            second_faction,
+            first_entity,
+            first_faction,
+            first_owner,
+        );
+
+        // Each body is pushed by velocity of the other one relative to it
+        for (entity, other_position, damage, source, direction) in [
+            (
+                first_entity,
+                second_transform.position(),
+                first_damage,
+                first_damage_source,
+                -relative_velocity,
+            ),
+            (
+                second_entity,
+                first_transform.position(),
+                second_damage,
+                second_damage_source,
+                relative_velocity,
+            ),
+        ] {
+            let Some(source) = source else {
                 continue;
             };
 
-        let mut entities = Vec::from(entities);
+            if damage <= 0.0 {
+                continue;
+            }
 
-        let (mut first_hp, first_entity) = entities.swap_remove(0);
-        let (mut second_hp, second_entity) = entities.swap_remove(0);
+            // Contact point is approximated by point of body nearest to the other body
+            let predicate = |candidate: Entity| candidate == entity;
+            let filter = QueryFilter::default().predicate(&predicate);
+            let point = rapier_context
+                .project_point(other_position, true, filter)
+                .map(|(_, projection)| projection.point)
+                .unwrap_or(other_position);
 
-        let impulse = e.total_force_magnitude / 100_000.0;
+            ev_damage.send(
+                DamageEvent::new(entity, damage, DamageKind::Collision)
+                    .with_source(Some(source))
+                    .with_hit(point, direction),
+            );
 
-        first_hp.damage(impulse);
-        second_hp.damage(impulse);
+            commands
+                .entity(entity)
+                .insert(TimedImmortalityBundle::new(IMMORTALITY_AFTER_COLLIDE_TIME));
+        }
+    }
+}
 
-        commands
-            .entity(first_entity)
-            .insert(TimedImmortalityBundle::new(IMMORTALITY_AFTER_COLLIDE_TIME));
+fn store_pre_step_velocity(mut q_entities: Query<(&Velocity, &mut PreStepVelocity)>) {
+    for (velocity, mut pre_step) in q_entities.iter_mut() {
+        *pre_step = PreStepVelocity::new(velocity.linvel);
+    }
+}
 
-        commands
-            .entity(second_entity)
-            .insert(TimedImmortalityBundle::new(IMMORTALITY_AFTER_COLLIDE_TIME));
+/// Returns entity credited for damage dealt to `target` by `attacker`, or None if damage is filtered.
+/// Projectiles never hit their shooter and follow friendly fire rules
+fn damage_source(
+    friendly_fire: &FriendlyFire,
+    target: Entity,
+    target_faction: Option<&Faction>,
+    attacker: Entity,
+    attacker_faction: Option<&Faction>,
+    attacker_owner: Option<&ProjectileOwner>,
+) -> Option<Entity> {
+    let Some(owner) = attacker_owner else {
+        return Some(attacker);
+    };
+
+    if owner.entity() == target || !friendly_fire.allows(attacker_faction, target_faction) {
+        return None;
     }
+
+    Some(owner.entity())
 }
 
 fn pause_physic(mut config: ResMut<RapierConfiguration>) {
@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::CollisionEvent;
+
+use crate::{
+    components::{
+        common::{Despawn, Reset, TimeToLive},
+        engine::Engine,
+        pickup::{BlinkBeforeDespawn, Pickup, PickupKind, PowerUps},
+        player::Player,
+        shield::Shield,
+        weapon::Weapon,
+    },
+    damage::HealEvent,
+    score::Score,
+    states::GameState,
+};
+
+/// Health restored by repair pickup
+const REPAIR_AMOUNT: f32 = 500.0;
+const RAPID_FIRE_MULTIPLIER: f32 = 2.0;
+const OVERDRIVE_MULTIPLIER: f32 = 1.5;
+const SCORE_MULTIPLIER: f32 = 2.0;
+
+/// Blink period in seconds of pickups about to disappear
+const BLINK_PERIOD: f32 = 0.25;
+
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                pickup_collect,
+                power_ups_update,
+                power_ups_effects,
+                pickup_blink,
+                power_ups_reset,
+            )
+                .in_set(OnUpdate(GameState::InGame)),
+        );
+    }
+}
+
+/// Player collects pickups by flying through them
+fn pickup_collect(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    q_pickups: Query<&Pickup, Without<Despawn>>,
+    mut q_player: Query<(&mut PowerUps, Option<&mut Shield>, Entity), With<Player>>,
+    mut ev_heal: EventWriter<HealEvent>,
+) {
+    // Ship can touch pickup with several colliders at once
+    let mut collected = Vec::new();
+
+    for e in collision_events.iter() {
+        let CollisionEvent::Started(first, second, _) = *e else {
+            continue;
+        };
+
+        let (pickup, other) = match q_pickups.contains(first) {
+            true => (first, second),
+            false => (second, first),
+        };
+
+        if collected.contains(&pickup) {
+            continue;
+        }
+
+        let Ok(kind) = q_pickups.get(pickup).map(|pickup| pickup.kind()) else {
+            continue;
+        };
+        let Ok((mut power_ups, shield, player)) = q_player.get_mut(other) else {
+            continue;
+        };
+
+        match kind {
+            PickupKind::Repair => ev_heal.send(HealEvent::new(player, REPAIR_AMOUNT)),
+            PickupKind::Shield => {
+                if let Some(mut shield) = shield {
+                    shield.recharge();
+                }
+            }
+            _ => {
+                if let Some(duration) = kind.duration() {
+                    power_ups.apply(kind, duration);
+                }
+            }
+        }
+
+        info!("Pickup collected: {}", kind.name());
+
+        commands.entity(pickup).insert(Despawn::Normal);
+        collected.push(pickup);
+    }
+}
+
+/// Ticks power-ups. Change is only signaled when some of them expired
+fn power_ups_update(time: Res<Time>, mut q_power_ups: Query<&mut PowerUps>) {
+    for mut power_ups in q_power_ups.iter_mut() {
+        if power_ups
+            .bypass_change_detection()
+            .update(time.delta_seconds())
+        {
+            power_ups.set_changed();
+        }
+    }
+}
+
+/// Applies active power-ups to weapons and engines of entity and to score
+fn power_ups_effects(
+    q_owners: Query<(&PowerUps, Option<&Player>, Entity), Changed<PowerUps>>,
+    q_children: Query<&Children>,
+    mut q_weapons: Query<&mut Weapon>,
+    mut q_engines: Query<&mut Engine>,
+    mut score: ResMut<Score>,
+) {
+    for (power_ups, player, entity) in q_owners.iter() {
+        let firerate = match power_ups.has(PickupKind::RapidFire) {
+            true => RAPID_FIRE_MULTIPLIER,
+            false => 1.0,
+        };
+        let force = match power_ups.has(PickupKind::Overdrive) {
+            true => OVERDRIVE_MULTIPLIER,
+            false => 1.0,
+        };
+
+        // Engines are attached through engine controller, so all descendants are checked
+        for descendant in q_children.iter_descendants(entity) {
+            if let Ok(mut weapon) = q_weapons.get_mut(descendant) {
+                weapon.set_firerate_multiplier(firerate);
+            }
+
+            if let Ok(mut engine) = q_engines.get_mut(descendant) {
+                engine.set_force_multiplier(force);
+            }
+        }
+
+        if player.is_some() {
+            score.set_bonus_multiplier(match power_ups.has(PickupKind::ScoreMultiplier) {
+                true => SCORE_MULTIPLIER,
+                false => 1.0,
+            });
+        }
+    }
+}
+
+fn pickup_blink(
+    mut q_pickups: Query<(&TimeToLive, &BlinkBeforeDespawn, &mut Visibility), With<Pickup>>,
+) {
+    for (time_to_live, blink, mut visibility) in q_pickups.iter_mut() {
+        let time_left = time_to_live.value();
+        if blink.time() < time_left {
+            continue;
+        }
+
+        *visibility = match (time_left / BLINK_PERIOD) as i32 % 2 {
+            0 => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn power_ups_reset(mut q_targets: Query<&mut PowerUps, With<Reset>>) {
+    for mut power_ups in q_targets.iter_mut() {
+        *power_ups = PowerUps::default();
+    }
+}
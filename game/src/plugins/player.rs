@@ -1,19 +1,25 @@
+use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
 
 use crate::components::camera::MainCamera;
-use crate::components::common::{Active, Resettable};
+use crate::components::cargo::CargoBundle;
+use crate::components::common::Resettable;
 
 use crate::components::movement::Axis;
 use crate::components::player::{Player, PlayerDecorator};
+use crate::components::shield::ShieldBuilder;
 use crate::components::ship::control::rotation::ShipTargetViewPoint;
 use crate::components::ship::control::ShipEngineController;
 use crate::components::ship::SimpleShipBuilder;
 use crate::components::ui::MainWindow;
-use crate::components::weapon::Weapon;
+use crate::components::weapon::{Magazine, Reload, WeaponGroup, WeaponLoadout};
 use crate::entity::{ComponentInjectorBuilder, EntityBuildDirector};
 
 use crate::states::GameState;
 
+/// Resource units player ship can carry
+const PLAYER_CARGO_CAPACITY: u32 = 30;
+
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
@@ -25,7 +31,9 @@ impl Plugin for PlayerPlugin {
                 throttle_backward,
                 sway_left,
                 sway_right,
-                fire_main,
+                fire_triggers,
+                switch_weapons,
+                reload_weapons,
             )
                 .in_set(OnUpdate(GameState::InGame)),
         );
@@ -33,9 +41,14 @@ impl Plugin for PlayerPlugin {
 }
 
 fn create_player_ship(mut commands: Commands) {
-    let ship_builder = SimpleShipBuilder::new(Vec2::ZERO);
+    let mut shield = ShieldBuilder::default();
+    shield.capacity(3000.0).regen_delay(4.0).regen_rate(300.0);
+
+    let ship_builder = SimpleShipBuilder::build_with(Vec2::ZERO, shield);
     let ship_builder = PlayerDecorator::new(ship_builder);
     let ship_builder = ComponentInjectorBuilder::new(ship_builder, Resettable);
+    let ship_builder =
+        ComponentInjectorBuilder::new(ship_builder, CargoBundle::new(PLAYER_CARGO_CAPACITY));
 
     commands.build_entity(&ship_builder);
 }
@@ -161,27 +174,77 @@ fn sway_left(
     }
 }
 
-fn fire_main(
+fn fire_triggers(
     key_state: Res<Input<MouseButton>>,
-    q_player: Query<&Children, With<Player>>,
-    q_weapon: Query<Entity, With<Weapon>>,
-    mut commands: Commands,
+    mut q_player: Query<&mut WeaponLoadout, With<Player>>,
 ) {
-    let children = q_player.single();
+    let mut loadout = q_player.single_mut();
+
+    let triggers = [
+        (MouseButton::Left, WeaponGroup::Primary),
+        (MouseButton::Right, WeaponGroup::Secondary),
+    ];
+
+    for (button, group) in triggers {
+        if key_state.just_pressed(button) {
+            loadout.pull(group);
+        } else if key_state.just_released(button) {
+            loadout.release(group);
+        }
+    }
+}
 
-    let weapon_option = children
-        .iter()
-        .find(|e| q_weapon.contains(**e))
-        .and_then(|e| Some(q_weapon.get(*e).unwrap()));
+fn switch_weapons(
+    key_state: Res<Input<KeyCode>>,
+    mut ev_wheel: EventReader<MouseWheel>,
+    mut q_player: Query<&mut WeaponLoadout, With<Player>>,
+) {
+    let mut loadout = q_player.single_mut();
+
+    let slot_keys = [
+        KeyCode::Key1,
+        KeyCode::Key2,
+        KeyCode::Key3,
+        KeyCode::Key4,
+        KeyCode::Key5,
+        KeyCode::Key6,
+        KeyCode::Key7,
+        KeyCode::Key8,
+        KeyCode::Key9,
+    ];
+
+    for (slot, key) in slot_keys.iter().enumerate() {
+        if key_state.just_pressed(*key) {
+            loadout.select(WeaponGroup::Primary, slot);
+        }
+    }
+
+    for ev in ev_wheel.iter() {
+        if 0.0 < ev.y {
+            loadout.previous(WeaponGroup::Primary);
+        } else if ev.y < 0.0 {
+            loadout.next(WeaponGroup::Primary);
+        }
+    }
+
+    if key_state.just_pressed(KeyCode::F) {
+        loadout.toggle_fire_mode();
+    }
+}
 
-    let Some(weapon) = weapon_option else {
-        warn!("Player doesn't have main weapon");
+fn reload_weapons(
+    key_state: Res<Input<KeyCode>>,
+    q_player: Query<&Children, With<Player>>,
+    q_magazines: Query<Entity, With<Magazine>>,
+    mut commands: Commands,
+) {
+    if !key_state.just_pressed(KeyCode::R) {
         return;
-    };
+    }
+
+    let children = q_player.single();
 
-    if key_state.just_pressed(MouseButton::Left) {
-        commands.entity(weapon).insert(Active);
-    } else if key_state.just_released(MouseButton::Left) {
-        commands.entity(weapon).remove::<Active>();
+    for weapon in children.iter().filter(|e| q_magazines.contains(**e)) {
+        commands.entity(*weapon).insert(Reload);
     }
 }
@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    components::{
+        cargo::Cargo,
+        common::{Despawn, Reset},
+        player::Player,
+        resource::ResourceFragment,
+    },
+    goal::GoalChangeEvent,
+    math::Position,
+    score::{Score, ScoreEvent},
+    states::GameState,
+};
+
+/// Points for every resource unit delivered to goal
+const RESOURCE_POINTS: u64 = 50;
+
+pub struct ResourcePlugin;
+
+impl Plugin for ResourcePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (resource_collect, cargo_mass, cargo_deliver, cargo_reset)
+                .in_set(OnUpdate(GameState::InGame)),
+        );
+    }
+}
+
+/// Player collects fragments into cargo by flying through them
+fn resource_collect(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut q_fragments: Query<&mut ResourceFragment, Without<Despawn>>,
+    mut q_cargo: Query<&mut Cargo, With<Player>>,
+) {
+    for e in collision_events.iter() {
+        let CollisionEvent::Started(first, second, _) = *e else {
+            continue;
+        };
+
+        let (fragment, other) = match q_fragments.contains(first) {
+            true => (first, second),
+            false => (second, first),
+        };
+
+        // Ship can touch fragment with several colliders at once, emptied fragment is skipped
+        let Ok(mut resource) = q_fragments.get_mut(fragment) else {
+            continue;
+        };
+        let Ok(mut cargo) = q_cargo.get_mut(other) else {
+            continue;
+        };
+
+        if resource.amount() == 0 || cargo.is_full() {
+            continue;
+        }
+
+        let loaded = cargo.load(resource.amount());
+        resource.take(loaded);
+
+        if resource.amount() == 0 {
+            commands.entity(fragment).insert(Despawn::Normal);
+        }
+    }
+}
+
+/// Loaded cargo makes ship heavier, so engines accelerate it slower
+fn cargo_mass(mut q_cargo: Query<(&Cargo, &mut AdditionalMassProperties), Changed<Cargo>>) {
+    for (cargo, mut mass) in q_cargo.iter_mut() {
+        *mass = AdditionalMassProperties::Mass(cargo.mass());
+    }
+}
+
+/// Cargo is sold for points at reached goal
+fn cargo_deliver(
+    mut ev_goal: EventReader<GoalChangeEvent>,
+    mut ev_score: EventWriter<ScoreEvent>,
+    mut score: ResMut<Score>,
+    mut prev_points: Local<u32>,
+    mut q_cargo: Query<(&mut Cargo, &Transform), With<Player>>,
+) {
+    for goal in ev_goal.iter() {
+        let reached = *prev_points < goal.points();
+        *prev_points = goal.points();
+
+        if !reached {
+            continue;
+        }
+
+        let Ok((mut cargo, transform)) = q_cargo.get_single_mut() else {
+            continue;
+        };
+
+        let amount = cargo.unload();
+        if 0 < amount {
+            let points = score.add(RESOURCE_POINTS * amount as u64);
+            ev_score.send(ScoreEvent::new(points, transform.position()));
+        }
+    }
+}
+
+fn cargo_reset(mut q_cargo: Query<&mut Cargo, With<Reset>>) {
+    for mut cargo in q_cargo.iter_mut() {
+        cargo.unload();
+    }
+}
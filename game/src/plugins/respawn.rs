@@ -5,8 +5,13 @@ use crate::components::player::Player;
 use crate::components::ship::Ship;
 use crate::components::ui::button::ButtonBuilder;
 use crate::entity::{ComponentInjectorBuilder, EntityChildBuildDirector};
+use crate::goal::Goal;
+use crate::plugins::achievement::AchievementsButton;
+use crate::plugins::highscore::HighScoreTable;
+use crate::score::Score;
 use crate::stages::LivingStages;
 use crate::states::GameState;
+use crate::stats::{format_duration, RunStats};
 
 const MENU_FONT: &str = "fonts/FiraMono-Medium.ttf";
 
@@ -51,8 +56,61 @@ fn button_style() -> Style {
     }
 }
 
-fn build_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn text_style() -> Style {
+    Style {
+        margin: UiRect {
+            left: Val::Auto,
+            right: Val::Auto,
+            bottom: Val::Px(30.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn stats_text(stats: &RunStats, score: &Score, goal: &Goal) -> String {
+    let asteroids = stats
+        .asteroids()
+        .map(|(level, count)| format!("L{}: {}", level, count))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "RUN STATISTICS\n\n\
+        Time survived       {}\n\
+        Distance            {:.0} units\n\
+        Goals reached       {}\n\
+        Asteroids destroyed {} ({})\n\
+        Shots fired         {} ({:.0}% accuracy)\n\
+        Damage taken        {:.0}\n\
+        Damage dealt        {:.0}\n\
+        Killed by           {}",
+        format_duration(score.time()),
+        stats.distance(),
+        goal.points,
+        stats.asteroids_total(),
+        asteroids,
+        stats.shots(),
+        stats.accuracy() * 100.0,
+        stats.damage_taken(),
+        stats.damage_dealt(),
+        stats.death_cause().map_or("-", |cause| cause.name()),
+    )
+}
+
+fn build_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    stats: Res<RunStats>,
+    score: Res<Score>,
+    goal: Res<Goal>,
+) {
     let font = asset_server.load(MENU_FONT);
+    let font_style = TextStyle {
+        font: font.clone(),
+        font_size: 20.0,
+        color: Color::WHITE,
+    };
 
     // Build respawn button
     let mut respawn_button_builder = ButtonBuilder::default();
@@ -64,6 +122,15 @@ fn build_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
     let respawn_button_builder =
         ComponentInjectorBuilder::new(respawn_button_builder, RespawnButton);
 
+    let mut achievements_button_builder = ButtonBuilder::default();
+    achievements_button_builder
+        .text("Achievements".to_string())
+        .font(font.clone())
+        .style(button_style());
+
+    let achievements_button_builder =
+        ComponentInjectorBuilder::new(achievements_button_builder, AchievementsButton);
+
     let mut exit_button_builder = ButtonBuilder::default();
     exit_button_builder
         .text("Exit".to_string())
@@ -94,7 +161,16 @@ fn build_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
             background_color: Color::rgba(0.0, 0.0, 0.0, 0.8).into(),
             ..Default::default()
         })
+        .with_children(|cs| {
+            cs.spawn(
+                TextBundle::from_section(stats_text(&stats, &score, &goal), font_style.clone())
+                    .with_style(text_style()),
+            );
+            cs.spawn(TextBundle::from_section("", font_style).with_style(text_style()))
+                .insert(HighScoreTable);
+        })
         .build_child_entity(&respawn_button_builder)
+        .build_child_entity(&achievements_button_builder)
         .build_child_entity(&exit_button_builder);
 }
 
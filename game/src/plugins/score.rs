@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        common::{
+            DespawnOnExitGame, Layer, MaxTimeToLive, PositionBundle, TimeToLive, TimeToLiveBundle,
+        },
+        player::Player,
+    },
+    goal::GoalChangeEvent,
+    math::Position,
+    score::{Score, ScoreEvent},
+    states::GameState,
+};
+
+const POPUP_FONT: &str = "fonts/FiraMono-Medium.ttf";
+/// Popup lifetime in seconds
+const POPUP_TIME: f32 = 1.0;
+/// Popup rise speed
+const POPUP_SPEED: f32 = 60.0;
+
+/// Floating "+N" text
+#[derive(Component)]
+struct ScorePopup;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .add_event::<ScoreEvent>()
+            .add_systems(
+                (
+                    score_update,
+                    score_goal_bonus,
+                    score_popup_spawn,
+                    score_popup_update,
+                )
+                    .in_set(OnUpdate(GameState::InGame)),
+            )
+            .add_system(score_reset.in_schedule(OnExit(GameState::Respawn)));
+    }
+}
+
+/// Ticks score timers. Change is only signaled when combo expired
+fn score_update(mut score: ResMut<Score>, time: Res<Time>) {
+    if score.bypass_change_detection().update(time.delta_seconds()) {
+        score.set_changed();
+    }
+}
+
+/// Gives bonus for quickly reached goal
+fn score_goal_bonus(
+    mut ev_goal: EventReader<GoalChangeEvent>,
+    mut ev_score: EventWriter<ScoreEvent>,
+    mut score: ResMut<Score>,
+    mut prev_points: Local<u32>,
+    q_player: Query<&Transform, With<Player>>,
+) {
+    for goal in ev_goal.iter() {
+        let reached = *prev_points < goal.points();
+        *prev_points = goal.points();
+
+        if !reached {
+            continue;
+        }
+
+        let bonus = score.goal_reached();
+        let Ok(transform) = q_player.get_single() else {
+            continue;
+        };
+
+        if 0 < bonus {
+            ev_score.send(ScoreEvent::new(bonus, transform.position()));
+        }
+    }
+}
+
+fn score_reset(mut score: ResMut<Score>) {
+    score.reset();
+}
+
+fn score_popup_spawn(
+    mut commands: Commands,
+    mut ev_score: EventReader<ScoreEvent>,
+    asset_server: Res<AssetServer>,
+) {
+    for ev in ev_score.iter() {
+        let style = TextStyle {
+            font: asset_server.load(POPUP_FONT),
+            font_size: 24.0,
+            color: Color::GOLD,
+        };
+
+        commands
+            .spawn(Text2dBundle {
+                text: Text::from_section(format!("+{}", ev.points), style)
+                    .with_alignment(TextAlignment::Center),
+                ..default()
+            })
+            .insert(PositionBundle::new(ev.position, Layer::Effects))
+            .insert(TimeToLiveBundle::new(POPUP_TIME))
+            .insert(DespawnOnExitGame)
+            .insert(ScorePopup);
+    }
+}
+
+/// Popups rise and fade out
+fn score_popup_update(
+    mut q_popups: Query<(&mut Transform, &mut Text, &TimeToLive, &MaxTimeToLive), With<ScorePopup>>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut text, time_to_live, max_time_to_live) in q_popups.iter_mut() {
+        transform.translation.y += POPUP_SPEED * time.delta_seconds();
+
+        let alpha = (time_to_live.value() / max_time_to_live.max()).clamp(0.0, 1.0);
+        for section in text.sections.iter_mut() {
+            section.style.color.set_a(alpha);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use bevy_prototype_lyon::prelude::*;
+
+use crate::{
+    components::{
+        common::Reset,
+        shield::{Shield, ShieldBubble},
+    },
+    states::GameState,
+};
+
+pub struct ShieldPlugin;
+
+impl Plugin for ShieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (shield_regenerate, shield_reset, shield_bubble_update)
+                .in_set(OnUpdate(GameState::InGame)),
+        );
+    }
+}
+
+fn shield_regenerate(mut q_shields: Query<&mut Shield>, time: Res<Time>) {
+    for mut shield in q_shields.iter_mut() {
+        shield.update(time.delta_seconds());
+    }
+}
+
+fn shield_reset(mut q_shields: Query<&mut Shield, With<Reset>>) {
+    for mut shield in q_shields.iter_mut() {
+        shield.recharge();
+    }
+}
+
+/// Bubble opacity tracks shield charge and flashes on impact
+fn shield_bubble_update(
+    q_shields: Query<&Shield>,
+    mut q_bubbles: Query<(&ShieldBubble, &Parent, &mut Fill)>,
+) {
+    for (bubble, parent, mut fill) in q_bubbles.iter_mut() {
+        let Ok(shield) = q_shields.get(parent.get()) else {
+            continue;
+        };
+
+        let alpha = bubble.alpha(shield.fraction(), shield.flash());
+        fill.color.set_a(alpha);
+    }
+}
@@ -12,6 +12,7 @@ use crate::components::ship::control::effects::*;
 use crate::components::ship::control::rotation::{RotationControl, ShipTargetViewPoint};
 use crate::components::ship::control::ShipEngineController;
 use crate::components::ship::Ship;
+use crate::components::status::Disabled;
 use crate::math::{Angle, Position, RotateAroundZ};
 use crate::stages::LivingStages;
 use crate::states::GameState;
@@ -52,16 +53,14 @@ impl Plugin for ShipPlugin {
     }
 }
 
+/// Rotates all controlled bodies (ships, missiles) to their target points
 fn ship_rotate_to_target(
-    mut q_ships: Query<
-        (
-            &Transform,
-            &ShipTargetViewPoint,
-            &mut RotationControl,
-            &Children,
-        ),
-        With<Ship>,
-    >,
+    mut q_ships: Query<(
+        &Transform,
+        &ShipTargetViewPoint,
+        &mut RotationControl,
+        &Children,
+    )>,
     mut q_controller: Query<&mut ShipEngineController>,
 ) {
     for (transform, target_point, mut rotation_control, children) in q_ships.iter_mut() {
@@ -179,11 +178,17 @@ fn fire_effect_disable(
 }
 
 fn ship_engine_controller<A: MovementAxis>(
-    q_controllers: Query<(&ShipEngineController, &Children)>,
+    q_controllers: Query<(&ShipEngineController, &Children, Option<&Parent>)>,
     mut q_engines: Query<&mut Engine, With<A>>,
+    q_disabled: Query<(), With<Disabled>>,
 ) {
-    for (controller, children) in q_controllers.iter() {
-        let throttle = controller.throttle(A::axis());
+    for (controller, children, parent) in q_controllers.iter() {
+        // Disabled ship can't use its engines
+        let disabled = parent.is_some_and(|parent| q_disabled.contains(parent.get()));
+        let throttle = match disabled {
+            true => 0.0,
+            false => controller.throttle(A::axis()),
+        };
 
         for child in children.iter() {
             if !q_engines.contains(*child) {
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::{
+    components::{
+        asteroid::{Asteroid, AsteroidSizeLevel},
+        faction::ProjectileOwner,
+        player::Player,
+        weapon::projectile::ProjectileHits,
+    },
+    damage::{AppliedDamageEvent, DamageKind, DeathEvent},
+    stages::LivingStages,
+    states::GameState,
+    stats::RunStats,
+};
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .add_systems((stats_distance, stats_shots).in_set(OnUpdate(GameState::InGame)))
+            // Damage is counted right after it is applied, before kills of the same update
+            .add_systems(
+                (stats_damage, stats_deaths)
+                    .chain()
+                    .in_set(LivingStages::DeadProcessing),
+            )
+            .add_system(stats_reset.in_schedule(OnExit(GameState::Respawn)));
+    }
+}
+
+fn stats_distance(
+    mut stats: ResMut<RunStats>,
+    q_player: Query<&Velocity, With<Player>>,
+    time: Res<Time>,
+) {
+    let Ok(velocity) = q_player.get_single() else {
+        return;
+    };
+
+    stats.travel(velocity.linvel.length() * time.delta_seconds());
+}
+
+/// Counts player bullets. Missiles are not counted as shots
+fn stats_shots(
+    mut stats: ResMut<RunStats>,
+    q_projectiles: Query<&ProjectileOwner, Added<ProjectileHits>>,
+    q_player: Query<Entity, With<Player>>,
+) {
+    let Ok(player) = q_player.get_single() else {
+        return;
+    };
+
+    for owner in q_projectiles.iter() {
+        if owner.entity() == player {
+            stats.shot();
+        }
+    }
+}
+
+/// Only damage that reduced health is counted: shield, immunity and overkill are ignored
+fn stats_damage(
+    mut stats: ResMut<RunStats>,
+    mut ev_damage: EventReader<AppliedDamageEvent>,
+    q_player: Query<Entity, With<Player>>,
+) {
+    let Ok(player) = q_player.get_single() else {
+        ev_damage.clear();
+        return;
+    };
+
+    for ev in ev_damage.iter() {
+        if ev.target == player {
+            stats.take_damage(ev.amount);
+            continue;
+        }
+
+        if ev.source != Some(player) {
+            continue;
+        }
+
+        stats.deal_damage(ev.amount);
+
+        if ev.kind == DamageKind::Projectile {
+            stats.hit();
+        }
+    }
+}
+
+/// Counts asteroids killed by player and remembers what killed player
+fn stats_deaths(
+    mut stats: ResMut<RunStats>,
+    mut ev_death: EventReader<DeathEvent>,
+    q_asteroids: Query<&AsteroidSizeLevel, With<Asteroid>>,
+    q_player: Query<Entity, With<Player>>,
+) {
+    let Ok(player) = q_player.get_single() else {
+        ev_death.clear();
+        return;
+    };
+
+    for death in ev_death.iter() {
+        if death.entity == player {
+            stats.died(death.cause);
+            continue;
+        }
+
+        if death.source != Some(player) {
+            continue;
+        }
+
+        if let Ok(size) = q_asteroids.get(death.entity) {
+            stats.asteroid_destroyed(size.level());
+        }
+    }
+}
+
+fn stats_reset(mut stats: ResMut<RunStats>) {
+    stats.reset();
+}
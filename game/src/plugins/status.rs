@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Damping;
+
+use crate::{
+    components::{
+        common::{Active, Reset},
+        health::{Armor, Dead, Health},
+        particle::{fire::FireParticleBuilder, ParticleGenerator, ParticleGeneratorBundle},
+        status::{
+            BaseDamping, BurningEffect, Disabled, StatusEffects, StatusKind, STATUS_TICK_PERIOD,
+        },
+    },
+    damage::{DamageEvent, DamageKind, StatusEvent},
+    states::GameState,
+};
+
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<StatusEvent>().add_systems(
+            (
+                status_apply,
+                status_update,
+                status_emp,
+                status_slowed,
+                status_corroded,
+                status_burning_effect,
+                status_reset,
+            )
+                .in_set(OnUpdate(GameState::InGame)),
+        );
+    }
+}
+
+/// Base damping is remembered with first effects of entity
+fn status_apply(
+    mut commands: Commands,
+    mut ev_status: EventReader<StatusEvent>,
+    mut q_targets: Query<Option<&mut StatusEffects>, (With<Health>, Without<Dead>)>,
+    q_damping: Query<&Damping>,
+) {
+    // Entities getting their first effects this update
+    let mut new_effects: HashMap<Entity, StatusEffects> = HashMap::new();
+
+    for ev in ev_status.iter() {
+        let Ok(effects) = q_targets.get_mut(ev.target) else {
+            continue;
+        };
+
+        match effects {
+            Some(mut effects) => effects.apply(ev.effect),
+            None => new_effects.entry(ev.target).or_default().apply(ev.effect),
+        }
+    }
+
+    for (entity, effects) in new_effects {
+        let base = q_damping
+            .get(entity)
+            .map_or(0.0, |damping| damping.linear_damping);
+
+        commands
+            .entity(entity)
+            .insert(effects)
+            .insert(BaseDamping(base));
+    }
+}
+
+/// Ticks effects. Burning deals damage every tick.
+/// Change is only signaled when effect expired, so durations tick without change detection
+fn status_update(
+    mut q_targets: Query<(&mut StatusEffects, Entity)>,
+    mut ev_damage: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    for (mut effects, entity) in q_targets.iter_mut() {
+        if effects.is_empty() {
+            continue;
+        }
+
+        let count = effects.len();
+        let ticks = effects
+            .bypass_change_detection()
+            .update(time.delta_seconds());
+
+        if effects.len() != count {
+            effects.set_changed();
+        }
+
+        for (effect, ticks) in ticks {
+            if effect.kind() != StatusKind::Burning || ticks == 0 {
+                continue;
+            }
+
+            let damage = effect.strength() * STATUS_TICK_PERIOD * ticks as f32;
+            ev_damage.send(
+                DamageEvent::new(entity, damage, DamageKind::Burning).with_source(effect.source()),
+            );
+        }
+    }
+}
+
+fn status_emp(
+    mut commands: Commands,
+    q_targets: Query<(&StatusEffects, Option<&Disabled>, Entity), Changed<StatusEffects>>,
+) {
+    for (effects, disabled, entity) in q_targets.iter() {
+        match (effects.has(StatusKind::Emp), disabled.is_some()) {
+            (true, false) => {
+                commands.entity(entity).insert(Disabled);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<Disabled>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Slow is added on top of base damping
+fn status_slowed(
+    mut commands: Commands,
+    mut q_targets: Query<
+        (&StatusEffects, &BaseDamping, Option<&mut Damping>, Entity),
+        Changed<StatusEffects>,
+    >,
+) {
+    for (effects, base, damping, entity) in q_targets.iter_mut() {
+        let linear_damping = base.0 + effects.strength(StatusKind::Slowed);
+
+        match damping {
+            Some(mut damping) if damping.linear_damping != linear_damping => {
+                damping.linear_damping = linear_damping;
+            }
+            None if 0.0 < linear_damping => {
+                commands.entity(entity).insert(Damping {
+                    linear_damping,
+                    angular_damping: 0.0,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+fn status_corroded(mut q_targets: Query<(&StatusEffects, &mut Armor), Changed<StatusEffects>>) {
+    for (effects, mut armor) in q_targets.iter_mut() {
+        armor.set_reduction(effects.strength(StatusKind::Corroded));
+    }
+}
+
+/// Spawns fire particles on burning entities and removes them when fire is out
+fn status_burning_effect(
+    mut commands: Commands,
+    q_targets: Query<(&StatusEffects, Option<&Children>, Entity), Changed<StatusEffects>>,
+    q_effects: Query<Entity, With<BurningEffect>>,
+) {
+    for (effects, children, entity) in q_targets.iter() {
+        let burning = effects.has(StatusKind::Burning);
+        let effect = children.and_then(|children| {
+            children
+                .iter()
+                .find(|child| q_effects.contains(**child))
+                .copied()
+        });
+
+        match (burning, effect) {
+            (true, None) => {
+                let generator = ParticleGenerator::new(FireParticleBuilder::new());
+
+                commands.entity(entity).with_children(|cb| {
+                    cb.spawn(ParticleGeneratorBundle::new(
+                        generator,
+                        3.0,
+                        5.0,
+                        Transform::default(),
+                    ))
+                    .insert(BurningEffect)
+                    .insert(Active);
+                });
+            }
+            (false, Some(effect)) => {
+                commands.entity(effect).despawn_recursive();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn status_reset(
+    mut commands: Commands,
+    q_targets: Query<Entity, (With<StatusEffects>, With<Reset>)>,
+) {
+    for entity in q_targets.iter() {
+        commands.entity(entity).insert(StatusEffects::default());
+    }
+}
@@ -1,15 +1,30 @@
-use bevy::prelude::*;
-use bevy_rapier2d::prelude::Velocity;
+use std::collections::HashMap;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_rapier2d::prelude::{CollisionEvent, QueryFilter, RapierContext, Velocity};
+use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
 
 use crate::{
     components::{
+        asteroid::Asteroid,
         common::{Active, Despawn},
+        faction::{Faction, FriendlyFire, ProjectileOwner},
         health::Dead,
+        movement::Axis,
+        particle::impact::ImpactBurstBundle,
+        ship::control::{rotation::ShipTargetViewPoint, ShipEngineController},
+        status::{Disabled, InflictStatus},
         weapon::{
-            projectile::{Projectile, ProjectileCreator},
-            Weapon,
+            projectile::{
+                missile::{Fuel, Missile, MissileTarget, Seeker},
+                Piercing, Projectile, ProjectileCreator, ProjectileDamage, ProjectileHits,
+                Ricochet,
+            },
+            AmmoReserve, FireMode, Magazine, Reload, Weapon, WeaponGroup, WeaponHeat,
+            WeaponLoadout, WeaponSlot,
         },
     },
+    damage::{DamageEvent, DamageKind, StatusEvent},
     entity::EntityBuildDirector,
     math::{Angle, Position, RotateAroundZ},
     random::Deviate,
@@ -21,8 +36,78 @@ pub struct WeaponPlugin;
 
 impl Plugin for WeaponPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems((weapon_update, fire_weapon).in_set(OnUpdate(GameState::InGame)))
-            .add_system(despawn_dead_projectiles.in_set(LivingStages::DeadProcessing));
+        app.add_systems(
+            (
+                loadout_update,
+                weapon_update,
+                magazine_update,
+                heat_update,
+                fire_weapon,
+            )
+                .in_set(OnUpdate(GameState::InGame)),
+        )
+        .add_systems(
+            (
+                projectile_hit,
+                missile_engine_burn,
+                missile_guidance,
+                missile_contact,
+            )
+                .in_set(OnUpdate(GameState::InGame)),
+        )
+        .add_system(despawn_dead_projectiles.in_set(LivingStages::DeadProcessing));
+    }
+}
+
+/// Activates weapons in selected slots of pulled triggers
+fn loadout_update(
+    mut commands: Commands,
+    q_ships: Query<(&WeaponLoadout, &Children), Changed<WeaponLoadout>>,
+    mut q_weapons: Query<(&WeaponGroup, &WeaponSlot, &mut Weapon, Option<&Active>)>,
+) {
+    for (loadout, children) in q_ships.iter() {
+        // Hardpoints that start firing grouped by weapon
+        let mut activated: HashMap<(WeaponGroup, usize), Vec<Entity>> = HashMap::new();
+
+        for child in children.iter() {
+            let Ok((group, slot, _, active)) = q_weapons.get(*child) else {
+                continue;
+            };
+
+            let should_fire = loadout.triggered(*group) && loadout.is_active(*group, *slot);
+
+            match (should_fire, active.is_some()) {
+                (true, false) => {
+                    commands.entity(*child).insert(Active);
+                    activated
+                        .entry((*group, slot.index()))
+                        .or_default()
+                        .push(*child);
+                }
+                (false, true) => {
+                    commands.entity(*child).remove::<Active>();
+                }
+                _ => {}
+            }
+        }
+
+        if loadout.fire_mode() != FireMode::Alternating {
+            continue;
+        }
+
+        // Stagger hardpoints so they fire one after another
+        for hardpoints in activated.values() {
+            let count = hardpoints.len() as f32;
+
+            for (index, entity) in hardpoints.iter().enumerate() {
+                let Ok((_, _, mut weapon, _)) = q_weapons.get_mut(*entity) else {
+                    continue;
+                };
+
+                let period = 1.0 / (weapon.firerate() * count);
+                weapon.delay(period * index as f32);
+            }
+        }
     }
 }
 
@@ -32,39 +117,334 @@ fn weapon_update(mut q_weapons: Query<&mut Weapon>, time: Res<Time>) {
     }
 }
 
+fn magazine_update(
+    mut commands: Commands,
+    mut q_weapons: Query<(
+        &mut Magazine,
+        Option<&mut AmmoReserve>,
+        Option<&Reload>,
+        Entity,
+    )>,
+    time: Res<Time>,
+) {
+    for (mut magazine, mut reserve, reload, entity) in q_weapons.iter_mut() {
+        let has_ammo = reserve.as_ref().is_none_or(|r| 0 < r.rounds());
+
+        if reload.is_some() {
+            commands.entity(entity).remove::<Reload>();
+
+            if has_ammo {
+                magazine.start_reload();
+            }
+        }
+
+        // Reload empty magazine automatically
+        if magazine.empty() && has_ammo {
+            magazine.start_reload();
+        }
+
+        if magazine.update(time.delta_seconds()) {
+            let missing = magazine.missing();
+            let rounds = match reserve.as_mut() {
+                Some(reserve) => reserve.take(missing),
+                None => missing,
+            };
+
+            magazine.refill(rounds);
+        }
+    }
+}
+
+fn heat_update(mut q_weapons: Query<&mut WeaponHeat>, time: Res<Time>) {
+    for mut heat in q_weapons.iter_mut() {
+        heat.update(time.delta_seconds());
+    }
+}
+
+/// Weapon with everything limiting its fire
+type FiringWeapon = (
+    &'static mut Weapon,
+    &'static mut ProjectileCreator,
+    &'static GlobalTransform,
+    &'static Parent,
+    Option<&'static mut Magazine>,
+    Option<&'static mut WeaponHeat>,
+);
+
 fn fire_weapon(
     mut commands: Commands,
-    q_parents: Query<&Velocity>,
-    mut q_weapons: Query<
+    q_parents: Query<(Option<&Velocity>, Option<&Faction>)>,
+    q_disabled: Query<(), With<Disabled>>,
+    mut q_weapons: Query<FiringWeapon, With<Active>>,
+) {
+    for (mut weapon, mut creator, transform, parent, mut magazine, mut heat) in q_weapons.iter_mut()
+    {
+        // Disabled ship can't use its weapons
+        if q_disabled.contains(parent.get()) {
+            continue;
+        }
+
+        let magazine_ready = magazine.as_ref().is_none_or(|m| m.can_fire());
+        let heat_ready = heat.as_ref().is_none_or(|h| h.can_fire());
+
+        if !magazine_ready || !heat_ready || !weapon.fire() {
+            continue;
+        }
+
+        if let Some(magazine) = magazine.as_mut() {
+            magazine.consume();
+        }
+
+        if let Some(heat) = heat.as_mut() {
+            heat.shot();
+        }
+
+        let mut rng = rand::thread_rng();
+
+        let base_angle = transform.angle();
+        let angle = base_angle.deviate(&mut rng, weapon.accuracy() as f64);
+
+        let velocity = Vec2::X.rotate_z(angle) * weapon.velocity();
+        let position = transform.position();
+
+        let (parent_velocity_option, faction) = q_parents.get(parent.get()).unwrap_or_default();
+        let parent_velocity = match parent_velocity_option {
+            Some(vel) => vel.linvel,
+            _ => Vec2::ZERO,
+        };
+
+        creator.set_position(position);
+        creator.set_velocity(velocity + parent_velocity);
+        creator.set_angle(angle);
+        creator.set_owner(parent.get(), faction.copied());
+
+        commands.build_entity(&*creator);
+    }
+}
+
+/// Projectile with everything its hits are resolved by
+type HittingProjectile = (
+    &'static ProjectileDamage,
+    &'static mut Piercing,
+    &'static mut ProjectileHits,
+    Option<&'static Ricochet>,
+    Option<&'static ProjectileOwner>,
+    Option<&'static InflictStatus>,
+    &'static Transform,
+    &'static mut Velocity,
+);
+
+/// Physics and rules projectile hits are resolved with
+#[derive(SystemParam)]
+struct HitContext<'w> {
+    rapier_context: Res<'w, RapierContext>,
+    friendly_fire: Res<'w, FriendlyFire>,
+    time: Res<'w, Time>,
+}
+
+/// Events projectiles send to what they hit
+#[derive(SystemParam)]
+struct ProjectileHitEvents<'w> {
+    damage: EventWriter<'w, DamageEvent>,
+    status: EventWriter<'w, StatusEvent>,
+}
+
+/// Resolves projectile hits: deals damage, pierces targets or bounces off them
+fn projectile_hit(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    context: HitContext,
+    mut q_projectiles: Query<HittingProjectile, (With<Projectile>, Without<Dead>)>,
+    q_obstacles: Query<Option<&Faction>, Without<Projectile>>,
+    q_factions: Query<&Faction, With<Projectile>>,
+    mut hit_events: ProjectileHitEvents,
+) {
+    for e in collision_events.iter() {
+        let CollisionEvent::Started(first, second, _) = e else {
+            continue;
+        };
+
+        let (projectile, target) = match q_projectiles.contains(*first) {
+            true => (*first, *second),
+            false => (*second, *first),
+        };
+
+        // Projectiles do not hit each other
+        let Ok(target_faction) = q_obstacles.get(target) else {
+            continue;
+        };
+
+        let Ok((damage, mut piercing, mut hits, ricochet, owner, status, transform, mut velocity)) =
+            q_projectiles.get_mut(projectile)
+        else {
+            continue;
+        };
+
+        let faction = q_factions.get(projectile).ok();
+        let owner_hit = owner.is_some_and(|owner| owner.entity() == target);
+
+        if hits.contains(target)
+            || owner_hit
+            || !context.friendly_fire.allows(faction, target_faction)
+        {
+            continue;
+        }
+
+        hits.push(target);
+
+        // Find hit point and surface normal by ray from position before the hit
+        let position = transform.position();
+        let direction = velocity.linvel.normalize_or_zero();
+        let backtrack = velocity.linvel.length() * context.time.delta_seconds() * 2.0 + 1.0;
+
+        let predicate = |candidate: Entity| candidate == target;
+        let filter = QueryFilter::default().predicate(&predicate);
+        let (hit_point, normal) = context
+            .rapier_context
+            .cast_ray_and_get_normal(
+                position - direction * backtrack,
+                direction,
+                backtrack * 2.0,
+                false,
+                filter,
+            )
+            .map(|(_, intersection)| (intersection.point, intersection.normal))
+            .unwrap_or((position, -direction));
+
+        // Glancing hit: bounce off without damage
+        if let Some(bounced) = ricochet.and_then(|r| r.bounce(velocity.linvel, normal)) {
+            velocity.linvel = bounced;
+            commands.spawn(ImpactBurstBundle::new(hit_point, bounced));
+            continue;
+        }
+
+        let source = owner.map_or(projectile, |owner| owner.entity());
+        hit_events.damage.send(
+            DamageEvent::new(target, damage.damage(), DamageKind::Projectile)
+                .with_source(Some(source))
+                .with_hit(hit_point, direction),
+        );
+
+        if let Some(status) = status {
+            hit_events.status.send(StatusEvent::new(
+                target,
+                status.effect().with_source(Some(source)),
+            ));
+        }
+
+        commands.spawn(ImpactBurstBundle::new(hit_point, normal));
+
+        if !piercing.pierce() {
+            commands.entity(projectile).insert(Dead);
+        }
+    }
+}
+
+/// Missile still flying
+type ActiveMissile = (With<Missile>, Without<Dead>);
+
+/// Keeps missile main engine on full throttle while it has fuel. Missile explodes when fuel runs out
+fn missile_engine_burn(
+    mut commands: Commands,
+    mut q_missiles: Query<(&mut Fuel, &Children, Entity), ActiveMissile>,
+    mut q_controller: Query<&mut ShipEngineController>,
+    time: Res<Time>,
+) {
+    for (mut fuel, children, entity) in q_missiles.iter_mut() {
+        fuel.burn(time.delta_seconds());
+
+        if fuel.empty() {
+            commands.entity(entity).insert(Dead);
+            continue;
+        }
+
+        for child in children.iter() {
+            let Ok(mut controller) = q_controller.get_mut(*child) else {
+                continue;
+            };
+
+            if controller.throttle(Axis::Main) < 1.0 {
+                controller.set_throttle(Axis::Main, 1.0);
+            }
+        }
+    }
+}
+
+/// Locks missile on target and points missile to it
+fn missile_guidance(
+    mut commands: Commands,
+    mut q_missiles: Query<
         (
-            &mut Weapon,
-            &mut ProjectileCreator,
-            &GlobalTransform,
-            &Parent,
+            &Transform,
+            &Seeker,
+            Option<&MissileTarget>,
+            &mut ShipTargetViewPoint,
+            Entity,
         ),
-        With<Active>,
+        ActiveMissile,
     >,
+    q_targets: Query<(&Transform, Entity), With<Asteroid>>,
 ) {
-    for (mut weapon, mut creator, transform, parent) in q_weapons.iter_mut() {
-        if weapon.fire() {
-            let mut rng = rand::thread_rng();
+    for (transform, seeker, target, mut view_point, entity) in q_missiles.iter_mut() {
+        let position = transform.position();
+        let direction = Vec2::X.rotate_z(transform.angle());
 
-            let base_angle = transform.angle();
-            let angle = base_angle.deviate(&mut rng, weapon.accuracy() as f64);
+        let locked = target.and_then(|target| q_targets.get(target.entity()).ok());
+        if let Some((target_transform, _)) = locked {
+            *view_point = target_transform.position().into();
+            continue;
+        }
 
-            let velocity = Vec2::X.rotate_z(angle) * weapon.velocity();
-            let position = transform.position();
+        // Target is lost or not locked yet: search for nearest target in seeker's view
+        let nearest = q_targets
+            .iter()
+            .map(|(target_transform, target)| (target_transform.position(), target))
+            .filter(|(target_position, _)| seeker.sees(position, direction, *target_position))
+            .min_by(|(lhs, _), (rhs, _)| {
+                let lhs_distance = lhs.distance_squared(position);
+                let rhs_distance = rhs.distance_squared(position);
+                lhs_distance.total_cmp(&rhs_distance)
+            });
 
-            let parent_velocity_option = q_parents.get(parent.get()).ok();
-            let parent_velocity = match parent_velocity_option {
-                Some(vel) => vel.linvel,
-                _ => Vec2::ZERO,
-            };
+        match nearest {
+            Some((target_position, target)) => {
+                commands.entity(entity).insert(MissileTarget::new(target));
+                *view_point = target_position.into();
+            }
+            None => {
+                commands.entity(entity).remove::<MissileTarget>();
+                *view_point = (position + direction * seeker.range()).into();
+            }
+        }
+    }
+}
 
-            creator.set_position(position);
-            creator.set_velocity(velocity + parent_velocity);
+/// Missile explodes on any solid contact except other projectiles and its owner
+fn missile_contact(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    q_missiles: Query<Option<&ProjectileOwner>, ActiveMissile>,
+    q_projectiles: Query<(), With<Projectile>>,
+) {
+    for e in collision_events.iter() {
+        let CollisionEvent::Started(first, second, flags) = e else {
+            continue;
+        };
+
+        // Sensors (pickups, resources, bullets) are not obstacles
+        if flags.contains(CollisionEventFlags::SENSOR) {
+            continue;
+        }
+
+        for (entity, other) in [(first, second), (second, first)] {
+            let Ok(owner) = q_missiles.get(*entity) else {
+                continue;
+            };
 
-            commands.build_entity(&*creator);
+            let owner_hit = owner.is_some_and(|owner| owner.entity() == *other);
+            if !owner_hit && !q_projectiles.contains(*other) {
+                commands.entity(*entity).insert(Dead);
+            }
         }
     }
 }
@@ -74,6 +454,6 @@ fn despawn_dead_projectiles(
     q_projectiles: Query<Entity, (With<Dead>, With<Projectile>)>,
 ) {
     for entity in q_projectiles.iter() {
-        commands.entity(entity).insert(Despawn::Normal);
+        commands.entity(entity).insert(Despawn::Recursive);
     }
 }
@@ -1,4 +1,4 @@
-use bevy::prelude::Vec2;
+use bevy::prelude::{Resource, Vec2};
 use rand::{prelude::Distribution, Rng};
 use statrs::distribution::Normal;
 
@@ -27,3 +27,55 @@ impl Deviate for Vec2 {
         }
     }
 }
+
+/// Seed of current run. Changed on every respawn
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct RunSeed(u64);
+
+impl RunSeed {
+    pub fn seed(&self) -> u64 {
+        self.0
+    }
+
+    pub fn reseed(&mut self) {
+        self.0 = rand::random();
+    }
+}
+
+impl Default for RunSeed {
+    fn default() -> Self {
+        Self(rand::random())
+    }
+}
+
+/// Values chosen randomly with probability proportional to their weights
+#[derive(Clone, Debug)]
+pub struct WeightedTable<T> {
+    entries: Vec<(T, f32)>,
+}
+
+impl<T> WeightedTable<T> {
+    pub fn new(entries: Vec<(T, f32)>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns None if table is empty or all weights are zero
+    pub fn choose<R: Rng>(&self, rng: &mut R) -> Option<&T> {
+        let total: f32 = self.entries.iter().map(|(_, weight)| weight.max(0.0)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut roll = rng.gen_range(0.0..total);
+        for (value, weight) in self.entries.iter() {
+            let weight = weight.max(0.0);
+            if roll < weight {
+                return Some(value);
+            }
+
+            roll -= weight;
+        }
+
+        self.entries.last().map(|(value, _)| value)
+    }
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+/// Points for destroying asteroid of first size level
+const KILL_POINTS: u64 = 100;
+/// Time in seconds after kill to continue combo
+const COMBO_WINDOW: f32 = 2.0;
+/// Multiplier increase per kill in combo
+const COMBO_STEP: f32 = 0.25;
+const COMBO_MAX_MULTIPLIER: f32 = 4.0;
+/// Goals reached faster than this time in seconds give bonus
+const GOAL_BONUS_TIME: f32 = 60.0;
+/// Bonus points for every second left of [GOAL_BONUS_TIME]
+const GOAL_BONUS_PER_SECOND: f32 = 20.0;
+
+/// Points of current run
+#[derive(Resource, Clone, Debug)]
+pub struct Score {
+    points: u64,
+    /// Kills in current combo
+    combo: u32,
+    /// Time left to continue combo
+    combo_time: f32,
+    /// Extra multiplier from power-ups, applied on top of combo
+    bonus_multiplier: f32,
+    /// Time since last goal reached
+    goal_time: f32,
+    /// Run duration in seconds
+    time: f32,
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self {
+            points: 0,
+            combo: 0,
+            combo_time: 0.0,
+            bonus_multiplier: 1.0,
+            goal_time: 0.0,
+            time: 0.0,
+        }
+    }
+}
+
+impl Score {
+    pub fn points(&self) -> u64 {
+        self.points
+    }
+
+    pub fn multiplier(&self) -> f32 {
+        let combo =
+            (1.0 + self.combo.saturating_sub(1) as f32 * COMBO_STEP).min(COMBO_MAX_MULTIPLIER);
+        combo * self.bonus_multiplier
+    }
+
+    /// Sets extra multiplier, e.g. by power-up. One disables it
+    pub fn set_bonus_multiplier(&mut self, multiplier: f32) {
+        self.bonus_multiplier = multiplier;
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Run duration in seconds
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Updates timers and returns true if combo expired, so multiplier changed
+    pub fn update(&mut self, time: f32) -> bool {
+        self.time += time;
+        self.goal_time += time;
+        self.combo_time -= time;
+
+        if self.combo_time <= 0.0 && 0 < self.combo {
+            self.combo = 0;
+            return true;
+        }
+
+        false
+    }
+
+    /// Adds points for destroyed asteroid of continuous `size` level and returns them
+    pub fn asteroid_destroyed(&mut self, size: f32) -> u64 {
+        self.combo += 1;
+        self.combo_time = COMBO_WINDOW;
+
+        let points = KILL_POINTS as f32 * size.max(0.0) * self.multiplier();
+        self.add(points as u64)
+    }
+
+    /// Adds bonus for quickly reached goal and returns it
+    pub fn goal_reached(&mut self) -> u64 {
+        let time_left = (GOAL_BONUS_TIME - self.goal_time).max(0.0);
+        self.goal_time = 0.0;
+
+        self.add((time_left * GOAL_BONUS_PER_SECOND) as u64)
+    }
+
+    /// Adds points and returns them
+    pub fn add(&mut self, points: u64) -> u64 {
+        self.points += points;
+        points
+    }
+}
+
+/// Sent when player gains points
+#[derive(Clone, Debug)]
+pub struct ScoreEvent {
+    pub points: u64,
+    /// Where points were earned, used to show popup
+    pub position: Vec2,
+}
+
+impl ScoreEvent {
+    pub fn new(points: u64, position: Vec2) -> Self {
+        Self { points, position }
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use crate::damage::DamageKind;
+
+/// Statistics of current run collected in [InGame](crate::states::GameState::InGame) state
+#[derive(Resource, Default, Clone, Debug)]
+pub struct RunStats {
+    /// Distance travelled by player in world units
+    distance: f32,
+    /// Destroyed asteroids by size level
+    asteroids: BTreeMap<i32, u32>,
+    /// Highest asteroid size level destroyed before player took any damage
+    flawless_level: i32,
+    /// Fired bullets
+    shots: u32,
+    /// Bullet hits dealing damage
+    hits: u32,
+    damage_taken: f32,
+    damage_dealt: f32,
+    /// Kind of damage killed player
+    death_cause: Option<DamageKind>,
+}
+
+impl RunStats {
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn asteroids(&self) -> impl Iterator<Item = (i32, u32)> + '_ {
+        self.asteroids.iter().map(|(level, count)| (*level, *count))
+    }
+
+    pub fn asteroids_total(&self) -> u32 {
+        self.asteroids.values().sum()
+    }
+
+    pub fn flawless_level(&self) -> i32 {
+        self.flawless_level
+    }
+
+    pub fn shots(&self) -> u32 {
+        self.shots
+    }
+
+    /// Part of shots hit target. Piercing bullets can hit several times, so it is clamped by 1
+    pub fn accuracy(&self) -> f32 {
+        if self.shots == 0 {
+            return 0.0;
+        }
+
+        (self.hits as f32 / self.shots as f32).min(1.0)
+    }
+
+    pub fn damage_taken(&self) -> f32 {
+        self.damage_taken
+    }
+
+    pub fn damage_dealt(&self) -> f32 {
+        self.damage_dealt
+    }
+
+    pub fn death_cause(&self) -> Option<DamageKind> {
+        self.death_cause
+    }
+
+    pub fn travel(&mut self, distance: f32) {
+        self.distance += distance;
+    }
+
+    pub fn asteroid_destroyed(&mut self, level: i32) {
+        *self.asteroids.entry(level).or_default() += 1;
+
+        if self.damage_taken <= 0.0 {
+            self.flawless_level = self.flawless_level.max(level);
+        }
+    }
+
+    pub fn shot(&mut self) {
+        self.shots += 1;
+    }
+
+    pub fn hit(&mut self) {
+        self.hits += 1;
+    }
+
+    pub fn take_damage(&mut self, amount: f32) {
+        self.damage_taken += amount;
+    }
+
+    pub fn deal_damage(&mut self, amount: f32) {
+        self.damage_dealt += amount;
+    }
+
+    pub fn died(&mut self, cause: DamageKind) {
+        self.death_cause = Some(cause);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Formats duration in seconds as MM:SS
+pub fn format_duration(seconds: f32) -> String {
+    let seconds = seconds as u64;
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+/// Game directory inside platform data dir
+const DATA_DIR: &str = "bevy-adg";
+
+/// Path to file in game data directory. None if platform has no data dir
+pub fn data_file(name: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(DATA_DIR).join(name))
+}
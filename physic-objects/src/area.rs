@@ -15,12 +15,12 @@ impl Area {
         self.0
     }
 
+    /// Area does not depend on winding order of points
     pub(crate) fn triangle(points: &[Vec2; 3]) -> Self {
         let side_1 = points[1] - points[0];
         let side_2 = points[2] - points[0];
-        let angle = side_1.angle_between(side_2);
 
-        let area = 0.5 * side_1.length() * side_2.length() * angle.cos();
+        let area = 0.5 * side_1.perp_dot(side_2).abs();
 
         Self(area)
     }
@@ -44,3 +44,51 @@ impl AddAssign for Area {
         *self = Self(self.0 + rhs.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_area() {
+        let points = [Vec2::ZERO, Vec2::X, Vec2::Y];
+        assert_eq!(Area::triangle(&points).area(), 0.5);
+
+        let points = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(5.0, 1.0),
+            Vec2::new(1.0, 4.0),
+        ];
+        assert_eq!(Area::triangle(&points).area(), 6.0);
+    }
+
+    #[test]
+    fn triangle_area_ignores_winding_order() {
+        let clockwise = [Vec2::ZERO, Vec2::Y, Vec2::X];
+        let counter_clockwise = [Vec2::ZERO, Vec2::X, Vec2::Y];
+
+        assert_eq!(
+            Area::triangle(&clockwise).area(),
+            Area::triangle(&counter_clockwise).area()
+        );
+    }
+
+    #[test]
+    fn triangle_fan_covers_square() {
+        // Same fan as trianglefan builder makes: triangles from center to each edge
+        let square = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+        ];
+
+        let mut area = Area::new(0.0);
+        for i in 0..square.len() {
+            let points = [Vec2::ZERO, square[i], square[(i + 1) % square.len()]];
+            area += Area::triangle(&points);
+        }
+
+        assert_eq!(area.area(), 4.0);
+    }
+}
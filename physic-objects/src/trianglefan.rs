@@ -48,11 +48,14 @@ impl PhysicObjectBuilder for Builder {
         }
 
         // Add last shape to create last triangle
-        let shape = Collider::triangle(
+        let last_points = [
             Vec2::ZERO,
             *self.points.last().unwrap(),
             *self.points.first().unwrap(),
-        );
+        ];
+        area += Area::triangle(&last_points);
+
+        let shape = Collider::triangle(last_points[0], last_points[1], last_points[2]);
         compound.push((Vec2::ZERO, 0.0, shape));
 
         let collider = Collider::compound(compound);